@@ -1,15 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token_interface::{self, TokenInterface};
+use anchor_spl::token_interface::spl_token_2022::{self, extension::ExtensionType};
 use mpl_token_metadata::types::DataV2;
 use mpl_token_metadata::instructions::{
     CreateMetadataAccountV3Cpi, 
+    CreateMetadataAccountV3CpiAccounts,
     CreateMetadataAccountV3InstructionArgs,
     CreateMasterEditionV3Cpi,
     CreateMasterEditionV3InstructionArgs,
+    UpdateMetadataAccountV2Cpi,
+    UpdateMetadataAccountV2CpiAccounts,
+    UpdateMetadataAccountV2InstructionArgs,
     UpdateMetadataAccountsV2Cpi,
     UpdateMetadataAccountsV2InstructionArgs
 };
 use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
@@ -20,484 +29,4925 @@ pub mod credVault {
     // Initialize an issuer account
     pub fn initialize_issuer(ctx: Context<InitializeIssuer>, bump: u8) -> Result<()> {
         let issuer_account = &mut ctx.accounts.issuer_account;
-        issuer_account.issuer_pubkey = ctx.accounts.authority.key();
+        issuer_account.issuer_pubkey = ctx.accounts.authority.key(); // Stable issuer ID, kept across key rotations
+        issuer_account.authority = ctx.accounts.authority.key();
         issuer_account.bump = bump;
-        issuer_account.is_verified = false; // Needs admin verification
+        issuer_account.status = IssuerStatus::Pending; // Needs admin verification
         issuer_account.created_at = Clock::get()?.unix_timestamp;
+        issuer_account.status_updated_at = issuer_account.created_at;
+        issuer_account.profile = IssuerProfile::default();
+        issuer_account.pending_profile = None;
+        // A root issuer until an accreditor takes it on. Roots can't accredit others
+        // until governance grants them a path length.
+        issuer_account.parent = None;
+        issuer_account.depth = 0;
+        issuer_account.path_len = 0;
+        issuer_account.name_account = None;
+        issuer_account.open_reports = 0;
         
         Ok(())
     }
 
-    // Mint a credential SBT to a student
-    pub fn mint_credential(
-        ctx: Context<MintCredential>,
-        skill_name: String,
-        issue_date: i64,
-        credential_uri: String,
-    ) -> Result<()> {
-        let credential_account = &mut ctx.accounts.credential_account;
-        credential_account.issuer_pubkey = ctx.accounts.issuer.issuer_pubkey;
-        credential_account.student_pubkey = ctx.accounts.student.key();
-        credential_account.skill_name = skill_name;
-        credential_account.issue_date = issue_date;
-        credential_account.credential_uri = credential_uri;
-        credential_account.is_soulbound = true; // Mark as non-transferable
-        credential_account.is_revoked = false; // Initially not revoked
-        credential_account.created_at = Clock::get()?.unix_timestamp;
-        credential_account.bump = *ctx.bumps.get("credential_account").unwrap();
-
-        // Verify the issuer is verified
+    // Move control of an issuer to a new key (current authority only). The issuer ID and every
+    // credential seeded on it are unchanged. The new key co-signs to prove it is held.
+    pub fn rotate_issuer_authority(ctx: Context<RotateIssuerAuthority>) -> Result<()> {
+        let issuer_account = &mut ctx.accounts.issuer_account;
+        let new_authority = ctx.accounts.new_authority.key();
         require!(
-            ctx.accounts.issuer.is_verified,
-            CredVaultError::IssuerNotVerified
+            new_authority != issuer_account.authority,
+            CredVaultError::InvalidNewAuthority
         );
 
-        // Verify that the issuer signing the transaction is the same as the verified issuer account
-        require!(
-            ctx.accounts.issuer_account.key() == ctx.accounts.issuer.key(),
-            CredVaultError::UnauthorizedIssuer
-        );
+        issuer_account.authority = new_authority;
 
-        // Create the NFT token for the credential
-        let mint = &ctx.accounts.credential_mint;
-        let token_program = &ctx.accounts.token_program;
-        let metadata_program = &ctx.accounts.metadata_program;
-        let token_metadata = &mut ctx.accounts.token_metadata;
-        let payer = &ctx.accounts.issuer_account;
-        let system_program = &ctx.accounts.system_program;
-        let rent = &ctx.accounts.rent;
+        msg!("Issuer {} authority rotated to {}", issuer_account.issuer_pubkey, new_authority);
+        Ok(())
+    }
 
-        // Create the metadata account
-        CreateMetadataAccountV3Cpi {
-            metadata: token_metadata.to_account_info().into(),
-            mint: mint.to_account_info().into(),
-            mint_authority: payer.to_account_info().into(),
-            payer: payer.to_account_info().into(),
-            update_authority: (payer.to_account_info().clone(), true.into()),
-            system_program: system_program.to_account_info().into(),
-            rent: rent.to_account_info().into(),
-        }
-        .invoke(&CreateMetadataAccountV3InstructionArgs {
-            data: anchor_lang::solana_program::program_pack::Pack::pack(
-                &mpl_token_metadata::state::DataV2 {
-                    name: ctx.accounts.credential_account.skill_name.clone(),
-                    symbol: "CRED".to_string(),
-                    uri: ctx.accounts.credential_account.credential_uri.clone(),
-                    seller_fee_basis_points: 0, // No resale rights
-                    creators: None,
-                    collection: None,
-                    uses: None,
-                },
-            )?,
-            is_mutable: false, // Non-mutable to maintain credential integrity
-            collection_details: None,
-        })?;
+    // Turn the issuer into a multisig: from now on every mint and revocation needs `threshold`
+    // of `officers` to approve a pending operation (issuer authority only, once)
+    pub fn configure_issuer_multisig(
+        ctx: Context<ConfigureIssuerMultisig>,
+        officers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        validate_officers(&officers, threshold)?;
 
-        // Create the master edition account to make it non-transferable (Soulbound)
-        let master_edition = &mut ctx.accounts.master_edition;
-        CreateMasterEditionV3Cpi {
-            edition: master_edition.to_account_info().into(),
-            mint: mint.to_account_info().into(),
-            update_authority: payer.to_account_info().into(),
-            mint_authority: payer.to_account_info().into(),
-            payer: payer.to_account_info().into(),
-            metadata: token_metadata.to_account_info().into(),
-            token_program: token_program.to_account_info().into(),
-            system_program: system_program.to_account_info().into(),
-            rent: rent.to_account_info().into(),
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.issuer_account = ctx.accounts.issuer_account.key();
+        multisig.officers = officers;
+        multisig.threshold = threshold;
+        multisig.operation_count = 0;
+        multisig.bump = ctx.bumps.multisig;
+        ctx.accounts.issuer_account.multisig_enabled = true;
+
+        msg!("Issuer multisig configured with {} officers, threshold {}", multisig.officers.len(), threshold);
+        Ok(())
+    }
+
+    // Propose an issuer operation (officer only). Counts as the proposer's approval.
+    pub fn propose_issuer_operation(
+        ctx: Context<ProposeIssuerOperation>,
+        kind: IssuerOperationKind,
+    ) -> Result<()> {
+        match &kind {
+            IssuerOperationKind::MintCredential { skill_name, credential_uri, expires_at, issue_date, .. } => {
+                require!(
+                    skill_name.len() <= IssuerOperation::MAX_SKILL_LEN
+                        && credential_uri.len() <= IssuerOperation::MAX_URI_LEN,
+                    CredVaultError::FieldTooLong
+                );
+                validate_expiry(*expires_at, *issue_date)?;
+            }
+            IssuerOperationKind::RevokeCredential { reason_uri, .. } => {
+                require!(
+                    reason_uri.len() <= RevocationEvent::MAX_URI_LEN,
+                    CredVaultError::FieldTooLong
+                );
+            }
+            IssuerOperationKind::CountersignCredential { .. } => {}
+            IssuerOperationKind::RenewCredential { .. } => {}
+            IssuerOperationKind::SetOfficers { officers, threshold } => {
+                validate_officers(officers, *threshold)?;
+            }
         }
-        .invoke(&CreateMasterEditionV3InstructionArgs {
-            max_supply: Some(0), // No supply means it's unique
-        })?;
 
-        // Mint one token to the student's token account (though it's soulbound, this creates the token)
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.credential_mint.to_account_info(),
-            to: ctx.accounts.credential_token_account.to_account_info(),
-            authority: ctx.accounts.issuer_account.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::mint_to(cpi_ctx, 1)?;
+        let multisig = &mut ctx.accounts.multisig;
+        let proposer = ctx.accounts.officer.key();
+        let operation = &mut ctx.accounts.operation;
+        operation.multisig = multisig.key();
+        operation.id = multisig.operation_count;
+        operation.proposer = proposer;
+        operation.kind = kind;
+        operation.approvals = vec![proposer];
+        operation.executed = false;
+        operation.created_at = Clock::get()?.unix_timestamp;
+        operation.executed_at = None;
+        operation.bump = ctx.bumps.operation;
 
-        msg!("Credential minted successfully!");
+        multisig.operation_count = multisig
+            .operation_count
+            .checked_add(1)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
 
+        msg!("Issuer operation {} proposed by {}", operation.id, proposer);
         Ok(())
     }
 
-    // Mint a proof-of-work NFT for completed projects or hackathon work
-    pub fn mint_proof_of_work(
-        ctx: Context<MintProofOfWork>,
-        project_title: String,
-        project_description: String,
-        github_link: String,
-        demo_link: String,
-    ) -> Result<()> {
-        let proof_of_work_account = &mut ctx.accounts.proof_of_work_account;
-        proof_of_work_account.student_pubkey = ctx.accounts.student.key();
-        proof_of_work_account.project_title = project_title.clone();
-        proof_of_work_account.project_description = project_description;
-        proof_of_work_account.github_link = github_link;
-        proof_of_work_account.demo_link = demo_link;
-        proof_of_work_account.timestamp = Clock::get()?.unix_timestamp;
-        proof_of_work_account.is_transferable = true; // Unlike SBTs, these can be transferred
-        proof_of_work_account.created_at = Clock::get()?.unix_timestamp;
-        proof_of_work_account.bump = *ctx.bumps.get("proof_of_work_account").unwrap();
-
-        // Create the NFT token for the proof of work
-        let mint = &ctx.accounts.proof_of_work_mint;
-        let token_program = &ctx.accounts.token_program;
-        let metadata_program = &ctx.accounts.metadata_program;
-        let token_metadata = &mut ctx.accounts.token_metadata;
-        let payer = &ctx.accounts.student; // Student pays for and owns this NFT
-        let system_program = &ctx.accounts.system_program;
-        let rent = &ctx.accounts.rent;
+    // Approve a pending issuer operation (officer only, once per officer)
+    pub fn approve_issuer_operation(ctx: Context<ApproveIssuerOperation>) -> Result<()> {
+        let officer = ctx.accounts.officer.key();
+        let operation = &mut ctx.accounts.operation;
 
-        // Create the metadata account
-        CreateMetadataAccountV3Cpi {
-            metadata: token_metadata.to_account_info().into(),
-            mint: mint.to_account_info().into(),
-            mint_authority: payer.to_account_info().into(),
-            payer: payer.to_account_info().into(),
-            update_authority: (payer.to_account_info().clone(), true.into()),
-            system_program: system_program.to_account_info().into(),
-            rent: rent.to_account_info().into(),
-        }
-        .invoke(&CreateMetadataAccountV3InstructionArgs {
-            data: anchor_lang::solana_program::program_pack::Pack::pack(
-                &mpl_token_metadata::state::DataV2 {
-                    name: proof_of_work_account.project_title.clone(),
-                    symbol: "POW".to_string(), // Proof of Work token
-                    uri: format!("https://arweave.net/{}", generate_proof_of_work_metadata_uri(&proof_of_work_account.project_title, proof_of_work_account.student_pubkey)), // In a real implementation, this would be an actual IPFS or Arweave link
-                    seller_fee_basis_points: 500, // 5% royalty for the creator
-                    creators: None,
-                    collection: None,
-                    uses: None,
-                },
-            )?,
-            is_mutable: true, // Allow updates to metadata if needed
-            collection_details: None,
-        })?;
+        require!(!operation.executed, CredVaultError::ProposalAlreadyExecuted);
+        require!(
+            !operation.approvals.contains(&officer),
+            CredVaultError::AlreadyApproved
+        );
+        operation.approvals.push(officer);
 
-        // Create the master edition account - this is what makes it a proper NFT, but it's transferable
-        let master_edition = &mut ctx.accounts.master_edition;
-        CreateMasterEditionV3Cpi {
-            edition: master_edition.to_account_info().into(),
-            mint: mint.to_account_info().into(),
-            update_authority: payer.to_account_info().into(),
-            mint_authority: payer.to_account_info().into(),
-            payer: payer.to_account_info().into(),
-            metadata: token_metadata.to_account_info().into(),
-            token_program: token_program.to_account_info().into(),
-            system_program: system_program.to_account_info().into(),
-            rent: rent.to_account_info().into(),
-        }
-        .invoke(&CreateMasterEditionV3InstructionArgs {
-            max_supply: Some(0), // This makes it a one-of-a-kind NFT, but still transferable
-        })?;
+        msg!("Issuer operation {} approved by {} ({} approvals)", operation.id, officer, operation.approvals.len());
+        Ok(())
+    }
 
-        // Mint one token to the student's token account
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.proof_of_work_mint.to_account_info(),
-            to: ctx.accounts.proof_of_work_token_account.to_account_info(),
-            authority: ctx.accounts.student.to_account_info(),
+    // Apply an approved change to the officer set (officer only). Mints and revocations
+    // are executed through mint_credential and revoke_credential instead.
+    pub fn execute_officer_change(ctx: Context<ExecuteOfficerChange>) -> Result<()> {
+        let IssuerOperationKind::SetOfficers { officers, threshold } = ctx.accounts.operation.kind.clone() else {
+            return err!(CredVaultError::OperationMismatch);
         };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::mint_to(cpi_ctx, 1)?;
+        consume_issuer_operation(
+            &ctx.accounts.multisig,
+            &mut ctx.accounts.operation,
+            ctx.accounts.officer.key(),
+            &IssuerOperationKind::SetOfficers { officers: officers.clone(), threshold },
+            Clock::get()?.unix_timestamp,
+        )?;
 
-        msg!("Proof of Work NFT minted successfully for project: {}", proof_of_work_account.project_title);
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.officers = officers;
+        multisig.threshold = threshold;
 
+        msg!("Issuer officers updated: {} officers, threshold {}", multisig.officers.len(), threshold);
         Ok(())
     }
 
-    // Verify a credential
-    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<bool> {
-        let credential = &ctx.accounts.credential_account;
-        
-        // Check if credential exists and is valid
-        let is_valid = !credential.is_revoked && credential.created_at > 0;
-        
-        msg!("Credential verification result: {}", is_valid);
-        Ok(is_valid)
-    }
+    // Claim a unique display name or domain for the issuer (issuer authority only). `name` must
+    // already be normalized; it is only shown to verifiers once admins approve the claim.
+    pub fn claim_issuer_name(ctx: Context<ClaimIssuerName>, name: String) -> Result<()> {
+        require!(IssuerName::is_normalized(&name), CredVaultError::InvalidIssuerName);
 
-    // Verify a proof-of-work NFT
-    pub fn verify_proof_of_work(ctx: Context<VerifyProofOfWork>) -> Result<bool> {
-        let proof_of_work = &ctx.accounts.proof_of_work_account;
-        
-        // Check if proof of work exists and is valid
-        let is_valid = proof_of_work.created_at > 0;
-        
-        msg!("Proof of Work verification result: {}", is_valid);
-        Ok(is_valid)
+        let issuer_account = &mut ctx.accounts.issuer_account;
+        require!(issuer_account.name_account.is_none(), CredVaultError::NameAlreadyClaimed);
+        issuer_account.name_account = Some(ctx.accounts.issuer_name.key());
+
+        let issuer_name = &mut ctx.accounts.issuer_name;
+        issuer_name.name = name;
+        issuer_name.issuer_pubkey = issuer_account.issuer_pubkey;
+        issuer_name.approved = false;
+        issuer_name.claimed_at = Clock::get()?.unix_timestamp;
+        issuer_name.approved_at = None;
+        issuer_name.bump = ctx.bumps.issuer_name;
+
+        msg!("Issuer {} claimed name {}", issuer_name.issuer_pubkey, issuer_name.name);
+        Ok(())
     }
 
-    // Update credential metadata (issuer only)
-    pub fn update_metadata(
-        ctx: Context<UpdateMetadata>,
-        new_skill_name: Option<String>,
-        new_credential_uri: Option<String>,
+    // Grant a staff key permission to act for the issuer (issuer authority only)
+    pub fn add_issuer_delegate(
+        ctx: Context<AddIssuerDelegate>,
+        permissions: u8,
+        allowed_skills: Vec<String>,
+        mint_quota: Option<u32>,
+        quota_period: i64,
+        expires_at: Option<i64>,
     ) -> Result<()> {
-        let credential_account = &mut ctx.accounts.credential_account;
-
-        // Verify this is called by the original issuer
         require!(
-            ctx.accounts.issuer.key() == credential_account.issuer_pubkey,
-            CredVaultError::UnauthorizedIssuer
+            permissions != 0 && permissions & !IssuerDelegate::ALL_PERMISSIONS == 0,
+            CredVaultError::InvalidDelegateConfig
+        );
+        require!(
+            allowed_skills.len() <= IssuerDelegate::MAX_SKILLS
+                && allowed_skills.iter().all(|skill| skill.len() <= IssuerDelegate::MAX_SKILL_LEN),
+            CredVaultError::FieldTooLong
+        );
+        require!(
+            mint_quota.is_none() || quota_period > 0,
+            CredVaultError::InvalidDelegateConfig
         );
 
-        // Update fields if provided
-        if let Some(skill_name) = new_skill_name {
-            credential_account.skill_name = skill_name;
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, CredVaultError::InvalidExpiry);
         }
-        
-        if let Some(credential_uri) = new_credential_uri {
-            credential_account.credential_uri = credential_uri;
+
+        let delegate = &mut ctx.accounts.delegate;
+        delegate.issuer_account = ctx.accounts.issuer_account.key();
+        delegate.delegate = ctx.accounts.delegate_key.key();
+        delegate.permissions = permissions;
+        delegate.allowed_skills = allowed_skills;
+        delegate.mint_quota = mint_quota;
+        delegate.quota_period = quota_period;
+        delegate.period_start = now;
+        delegate.minted_in_period = 0;
+        delegate.expires_at = expires_at;
+        delegate.created_at = now;
+        delegate.bump = ctx.bumps.delegate;
+
+        msg!("Delegate {} added with permissions {:#05b}", delegate.delegate, permissions);
+        Ok(())
+    }
+
+    // Remove a delegate and refund its rent (issuer authority only)
+    pub fn remove_issuer_delegate(ctx: Context<RemoveIssuerDelegate>) -> Result<()> {
+        msg!("Delegate {} removed", ctx.accounts.delegate.delegate);
+        Ok(())
+    }
+
+    // Set the issuer's public profile (issuer only). While the issuer is Pending the profile
+    // applies immediately; for a Verified issuer it is staged until admins approve it.
+    pub fn update_issuer_profile(ctx: Context<UpdateIssuerProfile>, profile: IssuerProfile) -> Result<()> {
+        profile.validate()?;
+
+        let issuer_account = &mut ctx.accounts.issuer_account;
+        match issuer_account.status {
+            IssuerStatus::Pending => {
+                issuer_account.profile = profile;
+                issuer_account.pending_profile = None;
+                msg!("Issuer profile updated");
+            }
+            IssuerStatus::Verified => {
+                issuer_account.pending_profile = Some(profile);
+                msg!("Issuer profile change staged for admin approval");
+            }
+            IssuerStatus::Suspended | IssuerStatus::Revoked => {
+                return err!(CredVaultError::ProfileLocked);
+            }
         }
 
-        // Update metadata on-chain as well using metaplex
-        let token_metadata = &mut ctx.accounts.token_metadata;
-        let update_authority = &ctx.accounts.issuer_account;
+        Ok(())
+    }
 
-        UpdateMetadataAccountsV2Cpi {
-            metadata: token_metadata.to_account_info().into(),
-            update_authority: update_authority.to_account_info().into(),
-            system_program: ctx.accounts.system_program.to_account_info().into(),
+    // Initialize the program-wide governance config (once, by the program's upgrade authority)
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admins: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(admins.len() <= MAX_ADMINS, CredVaultError::TooManyAdmins);
+        for (i, admin) in admins.iter().enumerate() {
+            require!(!admins[..i].contains(admin), CredVaultError::DuplicateAdmin);
         }
-        .invoke(&UpdateMetadataAccountsV2InstructionArgs {
-            new_update_authority: None,
-            data: Some(anchor_lang::solana_program::program_pack::Pack::pack(
-                &mpl_token_metadata::state::DataV2 {
-                    name: credential_account.skill_name.clone(),
-                    symbol: "CRED".to_string(),
-                    uri: credential_account.credential_uri.clone(),
-                    seller_fee_basis_points: 0,
-                    creators: None,
-                    collection: None,
-                    uses: None,
-                },
-            )?),
-            primary_sale_happened: None,
-            is_mutable: false, // Keep it immutable
-        })?;
+        require!(
+            threshold > 0 && threshold as usize <= admins.len(),
+            CredVaultError::InvalidThreshold
+        );
 
-        msg!("Metadata updated successfully!");
+        let config = &mut ctx.accounts.config;
+        config.admins = admins;
+        config.threshold = threshold;
+        config.proposal_count = 0;
+        config.bump = ctx.bumps.config;
+        config.created_at = Clock::get()?.unix_timestamp;
+
+        msg!("Config initialized with {} admins, threshold {}", config.admins.len(), threshold);
         Ok(())
     }
 
-    // Update proof-of-work metadata (student only)
-    pub fn update_proof_of_work_metadata(
-        ctx: Context<UpdateProofOfWorkMetadata>,
-        new_project_title: Option<String>,
-        new_project_description: Option<String>,
-        new_github_link: Option<String>,
-        new_demo_link: Option<String>,
+    // Propose moving an issuer to a new status, e.g. verifying or reinstating it (admin only)
+    pub fn propose_issuer_status(
+        ctx: Context<ProposeIssuerStatus>,
+        status: IssuerStatus,
     ) -> Result<()> {
-        let proof_of_work_account = &mut ctx.accounts.proof_of_work_account;
+        require!(
+            ctx.accounts.issuer_account.status.can_transition_to(status),
+            CredVaultError::InvalidIssuerTransition
+        );
+        let action = ProposalAction::SetIssuerStatus {
+            issuer_account: ctx.accounts.issuer_account.key(),
+            status,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
 
-        // Verify this is called by the original owner
+    // Propose applying a verified issuer's staged profile change (admin only)
+    pub fn propose_profile_approval(ctx: Context<ProposeProfileApproval>) -> Result<()> {
+        let pending_profile = ctx
+            .accounts
+            .issuer_account
+            .pending_profile
+            .as_ref()
+            .ok_or(CredVaultError::NoPendingProfile)?;
+
+        // Pin the exact profile being approved, so it cannot be swapped before execution
+        let action = ProposalAction::ApproveProfile {
+            issuer_account: ctx.accounts.issuer_account.key(),
+            profile_hash: pending_profile.hash()?,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose moving an issuer to a new key when the current one is lost or compromised (admin only)
+    pub fn propose_authority_recovery(
+        ctx: Context<ProposeAuthorityRecovery>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
         require!(
-            ctx.accounts.student.key() == proof_of_work_account.student_pubkey,
-            CredVaultError::UnauthorizedUpdate
+            new_authority != ctx.accounts.issuer_account.authority,
+            CredVaultError::InvalidNewAuthority
         );
+        let action = ProposalAction::RotateIssuerAuthority {
+            issuer_account: ctx.accounts.issuer_account.key(),
+            new_authority,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
 
-        // Update fields if provided
-        if let Some(title) = new_project_title {
-            proof_of_work_account.project_title = title;
+    // Propose approving an issuer's verification application, which verifies the issuer on execution (admin only)
+    pub fn propose_application_approval(
+        ctx: Context<ProposeApplicationApproval>,
+        reason_code: u16,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.application.status == ApplicationStatus::Pending,
+            CredVaultError::ApplicationNotPending
+        );
+        let action = ProposalAction::ApproveApplication {
+            application: ctx.accounts.application.key(),
+            reason_code,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose rejecting an issuer's verification application with a reason code (admin only).
+    // The issuer stays Pending and may apply again.
+    pub fn propose_application_rejection(
+        ctx: Context<ProposeApplicationRejection>,
+        reason_code: u16,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.application.status == ApplicationStatus::Pending,
+            CredVaultError::ApplicationNotPending
+        );
+        let action = ProposalAction::RejectApplication {
+            application: ctx.accounts.application.key(),
+            reason_code,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose approving an issuer's name claim (admin only)
+    pub fn propose_name_approval(ctx: Context<ProposeNameApproval>) -> Result<()> {
+        require!(!ctx.accounts.issuer_name.approved, CredVaultError::NameNotPending);
+        let action = ProposalAction::ApproveIssuerName {
+            issuer_name: ctx.accounts.issuer_name.key(),
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose rejecting a pending name claim (admin only). Executing it closes the claim, so the
+    // name becomes free to claim again.
+    pub fn propose_name_rejection(ctx: Context<ProposeNameRejection>) -> Result<()> {
+        require!(!ctx.accounts.issuer_name.approved, CredVaultError::NameNotPending);
+        let action = ProposalAction::RejectIssuerName {
+            issuer_name: ctx.accounts.issuer_name.key(),
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose slashing an issuer's stake to the treasury or an affected party (admin only).
+    // Executing it resolves one pending stake case.
+    pub fn propose_stake_slash(
+        ctx: Context<ProposeStakeSlash>,
+        amount: u64,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        let issuer_stake = &ctx.accounts.issuer_stake;
+        require!(
+            amount > 0 && amount <= issuer_stake.slashable(),
+            CredVaultError::InsufficientStake
+        );
+        let action = ProposalAction::SlashStake {
+            issuer_stake: issuer_stake.key(),
+            amount,
+            recipient,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose extending a verified issuer's verification to `verified_until`, including
+    // after it has lapsed (admin only)
+    pub fn propose_verification_renewal(
+        ctx: Context<ProposeVerificationRenewal>,
+        verified_until: i64,
+    ) -> Result<()> {
+        validate_verification_renewal(verified_until, Clock::get()?.unix_timestamp)?;
+        let action = ProposalAction::RenewVerification {
+            issuer_account: ctx.accounts.issuer_account.key(),
+            verified_until,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose letting a root issuer accredit sub-issuers `path_len` levels deep (admin only)
+    pub fn propose_accreditation_rights(
+        ctx: Context<ProposeAccreditationRights>,
+        path_len: u8,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.issuer_account.parent.is_none() && path_len <= MAX_ISSUER_DEPTH,
+            CredVaultError::AccreditationDepthExceeded
+        );
+        let action = ProposalAction::GrantAccreditationRights {
+            issuer_account: ctx.accounts.issuer_account.key(),
+            path_len,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose paying out lamports collected in the treasury (admin only)
+    pub fn propose_treasury_withdrawal(
+        ctx: Context<ProposeTreasuryWithdrawal>,
+        amount: u64,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        require!(
+            amount > 0 && amount <= treasury_withdrawable(&ctx.accounts.treasury)?,
+            CredVaultError::InsufficientTreasuryBalance
+        );
+        let action = ProposalAction::WithdrawTreasury { amount, recipient };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose resolving an open fraud report as dismissed, credential revoked or issuer
+    // suspended, with a reason code (admin only). Revoking needs a credential report.
+    pub fn propose_fraud_report_resolution(
+        ctx: Context<ProposeFraudReportResolution>,
+        resolution: ReportStatus,
+        reason_code: u16,
+    ) -> Result<()> {
+        let fraud_report = &ctx.accounts.fraud_report;
+        validate_report_resolution(fraud_report, resolution)?;
+        let action = ProposalAction::ResolveFraudReport {
+            fraud_report: fraud_report.key(),
+            resolution,
+            reason_code,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose upholding or overturning a revocation under appeal, with a reason code (admin only)
+    pub fn propose_appeal_resolution(
+        ctx: Context<ProposeAppealResolution>,
+        overturn: bool,
+        reason_code: u16,
+    ) -> Result<()> {
+        let action = ProposalAction::ResolveAppeal {
+            appeal: ctx.accounts.appeal.key(),
+            overturn,
+            reason_code,
+        };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Propose adding/removing an admin or changing the threshold (admin only)
+    pub fn propose_admin_change(
+        ctx: Context<ProposeAdminChange>,
+        change: AdminChange,
+    ) -> Result<()> {
+        let action = ProposalAction::ChangeAdmins { change };
+        let bump = ctx.bumps.proposal;
+        open_proposal(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.proposal,
+            ctx.accounts.proposer.key(),
+            action,
+            bump,
+        )
+    }
+
+    // Approve a pending proposal (admin only, once per admin)
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let approver = ctx.accounts.approver.key();
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(!proposal.executed, CredVaultError::ProposalAlreadyExecuted);
+        require!(
+            !proposal.approvals.contains(&approver),
+            CredVaultError::AlreadyApproved
+        );
+        proposal.approvals.push(approver);
+
+        msg!("Proposal {} approved by {} ({} approvals)", proposal.id, approver, proposal.approvals.len());
+        Ok(())
+    }
+
+    // Execute a proposal once it has enough approvals from current admins
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(!proposal.executed, CredVaultError::ProposalAlreadyExecuted);
+
+        // Approvals from admins that have since been removed no longer count
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approver| config.admins.contains(approver))
+            .count();
+        require!(
+            approvals >= config.threshold as usize,
+            CredVaultError::InsufficientApprovals
+        );
+
+        match proposal.action.clone() {
+            ProposalAction::SetIssuerStatus { issuer_account: target, status } => {
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_account.key() == target,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                let now = Clock::get()?.unix_timestamp;
+                set_issuer_status(issuer_account, status, now, now)?;
+                msg!("Issuer {} status set to {:?}", issuer_account.issuer_pubkey, status);
+            }
+            ProposalAction::ChangeAdmins { change } => {
+                apply_admin_change(config, change)?;
+            }
+            ProposalAction::ApproveApplication { application: target, reason_code } => {
+                let application = ctx
+                    .accounts
+                    .application
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    application.key() == target && application.issuer_account == issuer_account.key(),
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(
+                    application.status == ApplicationStatus::Pending,
+                    CredVaultError::ApplicationNotPending
+                );
+
+                let now = Clock::get()?.unix_timestamp;
+                set_issuer_status(issuer_account, IssuerStatus::Verified, now, now)?;
+                issuer_account.has_open_application = false;
+
+                application.status = ApplicationStatus::Approved;
+                application.reason_code = Some(reason_code);
+                application.decided_by = Some(ctx.accounts.executor.key());
+                application.proposal_id = Some(proposal.id);
+                application.decided_at = Some(now);
+                msg!("Application {} approved, issuer {} verified", application.id, issuer_account.issuer_pubkey);
+            }
+            ProposalAction::RejectApplication { application: target, reason_code } => {
+                let application = ctx
+                    .accounts
+                    .application
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    application.key() == target && application.issuer_account == issuer_account.key(),
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(
+                    application.status == ApplicationStatus::Pending,
+                    CredVaultError::ApplicationNotPending
+                );
+
+                issuer_account.has_open_application = false;
+
+                application.status = ApplicationStatus::Rejected;
+                application.reason_code = Some(reason_code);
+                application.decided_by = Some(ctx.accounts.executor.key());
+                application.proposal_id = Some(proposal.id);
+                application.decided_at = Some(Clock::get()?.unix_timestamp);
+                msg!("Application {} rejected with reason {}", application.id, reason_code);
+            }
+            ProposalAction::ApproveProfile { issuer_account: target, profile_hash } => {
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_account.key() == target,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                let pending_profile = issuer_account
+                    .pending_profile
+                    .take()
+                    .ok_or(CredVaultError::NoPendingProfile)?;
+                require!(
+                    pending_profile.hash()? == profile_hash,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                issuer_account.profile = pending_profile;
+                msg!("Profile of issuer {} approved", issuer_account.issuer_pubkey);
+            }
+            ProposalAction::RotateIssuerAuthority { issuer_account: target, new_authority } => {
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_account.key() == target,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                issuer_account.authority = new_authority;
+                msg!("Issuer {} authority recovered to {}", issuer_account.issuer_pubkey, new_authority);
+            }
+            ProposalAction::ApproveIssuerName { issuer_name: target } => {
+                let issuer_name = ctx
+                    .accounts
+                    .issuer_name
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_name.key() == target,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(!issuer_name.approved, CredVaultError::NameNotPending);
+                issuer_name.approved = true;
+                issuer_name.approved_at = Some(Clock::get()?.unix_timestamp);
+                msg!("Name {} approved for issuer {}", issuer_name.name, issuer_name.issuer_pubkey);
+            }
+            ProposalAction::RejectIssuerName { issuer_name: target } => {
+                let issuer_name = ctx
+                    .accounts
+                    .issuer_name
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let claimant = ctx
+                    .accounts
+                    .recipient
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_name.key() == target
+                        && issuer_account.issuer_pubkey == issuer_name.issuer_pubkey
+                        && claimant.key() == issuer_account.authority,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(!issuer_name.approved, CredVaultError::NameNotPending);
+
+                // The claim's rent goes back to the issuer's authority
+                issuer_account.name_account = None;
+                issuer_name.close(claimant.to_account_info())?;
+                msg!("Name claim {} rejected", issuer_name.name);
+            }
+            ProposalAction::SlashStake { issuer_stake: target, amount, recipient: recipient_key } => {
+                let issuer_stake = ctx
+                    .accounts
+                    .issuer_stake
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let recipient = ctx
+                    .accounts
+                    .recipient
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_stake.key() == target && recipient.key() == recipient_key,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(amount <= issuer_stake.slashable(), CredVaultError::InsufficientStake);
+
+                // Bonded stake is taken first, then stake that is still unbonding
+                let from_bonded = amount.min(issuer_stake.amount);
+                issuer_stake.amount -= from_bonded;
+                issuer_stake.unbonding_amount -= amount - from_bonded;
+                if issuer_stake.unbonding_amount == 0 {
+                    issuer_stake.unbonding_available_at = None;
+                }
+                issuer_stake.pending_cases = issuer_stake.pending_cases.saturating_sub(1);
+                issuer_stake.total_slashed = issuer_stake
+                    .total_slashed
+                    .checked_add(amount)
+                    .ok_or(CredVaultError::ArithmeticOverflow)?;
+
+                **issuer_stake.to_account_info().try_borrow_mut_lamports()? -= amount;
+                **recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+                msg!("Slashed {} from {} to {}", amount, issuer_stake.issuer_account, recipient_key);
+            }
+            ProposalAction::RenewVerification { issuer_account: target, verified_until } => {
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_account.key() == target,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(
+                    issuer_account.status == IssuerStatus::Verified,
+                    CredVaultError::IssuerNotVerified
+                );
+                validate_verification_renewal(verified_until, Clock::get()?.unix_timestamp)?;
+                issuer_account.verified_until = Some(verified_until);
+                msg!("Issuer {} verified until {}", issuer_account.issuer_pubkey, verified_until);
+            }
+            ProposalAction::GrantAccreditationRights { issuer_account: target, path_len } => {
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    issuer_account.key() == target,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                // Sub-issuers get their path length from their accreditor
+                require!(issuer_account.parent.is_none(), CredVaultError::AccreditationDepthExceeded);
+                issuer_account.path_len = path_len;
+                msg!("Issuer {} may accredit {} levels deep", issuer_account.issuer_pubkey, path_len);
+            }
+            ProposalAction::WithdrawTreasury { amount, recipient: recipient_key } => {
+                let treasury = ctx
+                    .accounts
+                    .treasury
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let recipient = ctx
+                    .accounts
+                    .recipient
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let system_program = ctx
+                    .accounts
+                    .system_program
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(recipient.key() == recipient_key, CredVaultError::ProposalTargetMismatch);
+                require!(
+                    amount <= treasury_withdrawable(treasury)?,
+                    CredVaultError::InsufficientTreasuryBalance
+                );
+
+                // The treasury is a system account, so the system program moves its lamports
+                let treasury_bump = ctx.bumps.treasury.ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let treasury_seeds: &[&[u8]] = &[b"treasury", &[treasury_bump]];
+                anchor_lang::system_program::transfer(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: treasury.to_account_info(),
+                            to: recipient.to_account_info(),
+                        },
+                        &[treasury_seeds],
+                    ),
+                    amount,
+                )?;
+                msg!("Withdrew {} from the treasury to {}", amount, recipient_key);
+            }
+            ProposalAction::ResolveFraudReport { fraud_report: target, resolution, reason_code } => {
+                let fraud_report = ctx
+                    .accounts
+                    .fraud_report
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let issuer_account = ctx
+                    .accounts
+                    .issuer_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    fraud_report.key() == target && fraud_report.issuer_account == issuer_account.key(),
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(
+                    ctx.accounts.credential_account.as_ref().map(|credential_account| credential_account.key())
+                        == fraud_report.credential_account,
+                    CredVaultError::InvalidReportTarget
+                );
+                validate_report_resolution(fraud_report, resolution)?;
+
+                let now = Clock::get()?.unix_timestamp;
+                match ctx.accounts.credential_account.as_mut() {
+                    Some(credential_account) => {
+                        credential_account.open_reports = credential_account.open_reports.saturating_sub(1);
+                    }
+                    None => {
+                        issuer_account.open_reports = issuer_account.open_reports.saturating_sub(1);
+                    }
+                }
+
+                match resolution {
+                    ReportStatus::Dismissed => {}
+                    ReportStatus::CredentialRevoked => {
+                        let credential_account = ctx
+                            .accounts
+                            .credential_account
+                            .as_mut()
+                            .ok_or(CredVaultError::InvalidReportResolution)?;
+                        let system_program = ctx
+                            .accounts
+                            .system_program
+                            .as_ref()
+                            .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                        if !credential_account.is_revoked {
+                            credential_account.is_revoked = true;
+                            credential_account.revoked_at = Some(now);
+                            record_revocation_event(
+                                credential_account,
+                                RevocationEvent {
+                                    action: RevocationAction::Revoked,
+                                    reason_code: Some(reason_code),
+                                    uri: Some(fraud_report.evidence_uri.clone()),
+                                    actor: ctx.accounts.executor.key(),
+                                    at: now,
+                                },
+                                &ctx.accounts.executor,
+                                system_program,
+                            )?;
+                        }
+                    }
+                    ReportStatus::IssuerSuspended => {
+                        if issuer_account.status != IssuerStatus::Suspended {
+                            set_issuer_status(issuer_account, IssuerStatus::Suspended, now, now)?;
+                        }
+                    }
+                }
+
+                // An upheld report opens a case against the issuer's stake. For an issuer
+                // without a vault, its uninitialised address must be passed in
+                // `remaining_accounts` instead, proving there is no stake to hold.
+                if resolution != ReportStatus::Dismissed {
+                    match ctx.accounts.issuer_stake.as_mut() {
+                        Some(issuer_stake) => {
+                            require!(
+                                issuer_stake.issuer_account == issuer_account.key(),
+                                CredVaultError::ProposalTargetMismatch
+                            );
+                            issuer_stake.pending_cases = issuer_stake
+                                .pending_cases
+                                .checked_add(1)
+                                .ok_or(CredVaultError::ArithmeticOverflow)?;
+                        }
+                        None => {
+                            let (stake_key, _) = Pubkey::find_program_address(
+                                &[b"stake", issuer_account.key().as_ref()],
+                                ctx.program_id,
+                            );
+                            let vault = ctx
+                                .remaining_accounts
+                                .iter()
+                                .find(|account| account.key() == stake_key)
+                                .ok_or(CredVaultError::IssuerStakeRequired)?;
+                            require!(
+                                vault.owner == &System::id() && vault.data_is_empty(),
+                                CredVaultError::IssuerStakeRequired
+                            );
+                        }
+                    }
+                }
+
+                // Closing the report returns its bond and rent to the reporter, unless a
+                // dismissal forfeits the bond, in which case both go to the treasury
+                let destination = if resolution == ReportStatus::Dismissed && fraud_report.bond > 0 {
+                    ctx.accounts
+                        .treasury
+                        .as_ref()
+                        .ok_or(CredVaultError::ProposalTargetMismatch)?
+                        .to_account_info()
+                } else {
+                    let recipient = ctx
+                        .accounts
+                        .recipient
+                        .as_ref()
+                        .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                    require!(
+                        recipient.key() == fraud_report.reporter,
+                        CredVaultError::ProposalTargetMismatch
+                    );
+                    recipient.to_account_info()
+                };
+                fraud_report.close(destination)?;
+                msg!(
+                    "Fraud report against {} resolved as {:?} with reason {}",
+                    fraud_report.target(),
+                    resolution,
+                    reason_code
+                );
+            }
+            ProposalAction::ResolveAppeal { appeal: target, overturn, reason_code } => {
+                let appeal = ctx
+                    .accounts
+                    .appeal
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let credential_account = ctx
+                    .accounts
+                    .credential_account
+                    .as_mut()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let student = ctx
+                    .accounts
+                    .recipient
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                let system_program = ctx
+                    .accounts
+                    .system_program
+                    .as_ref()
+                    .ok_or(CredVaultError::ProposalTargetMismatch)?;
+                require!(
+                    appeal.key() == target
+                        && credential_account.key() == appeal.credential_account
+                        && student.key() == appeal.student,
+                    CredVaultError::ProposalTargetMismatch
+                );
+                require!(credential_account.appeal_pending(), CredVaultError::AppealNotPending);
+
+                // Overturning restores the credential's validity. Either way the decision is
+                // kept in the history and the appeal's rent returned to the student.
+                let now = Clock::get()?.unix_timestamp;
+                let action = if overturn {
+                    credential_account.is_revoked = false;
+                    credential_account.revoked_at = None;
+                    RevocationAction::AppealOverturned
+                } else {
+                    RevocationAction::AppealUpheld
+                };
+                record_revocation_event(
+                    credential_account,
+                    RevocationEvent {
+                        action,
+                        reason_code: Some(reason_code),
+                        uri: None,
+                        actor: ctx.accounts.executor.key(),
+                        at: now,
+                    },
+                    &ctx.accounts.executor,
+                    system_program,
+                )?;
+                appeal.close(student.to_account_info())?;
+                msg!("Appeal for {} resolved: {:?}", credential_account.key(), action);
+            }
         }
-        
-        if let Some(description) = new_project_description {
-            proof_of_work_account.project_description = description;
+
+        proposal.executed = true;
+        proposal.executed_at = Some(Clock::get()?.unix_timestamp);
+
+        msg!("Proposal {} executed", proposal.id);
+        Ok(())
+    }
+
+    // Submit an application for verification, recorded on-chain for auditing (issuer only).
+    // The optional fee is paid into the program treasury. `stake` lamports are bonded in the
+    // issuer's stake vault, which must then hold at least MIN_ISSUER_STAKE.
+    pub fn apply_for_verification(
+        ctx: Context<ApplyForVerification>,
+        organization_name: String,
+        document_uri: String,
+        document_hash: [u8; 32],
+        fee: Option<u64>,
+        stake: u64,
+    ) -> Result<()> {
+        require!(
+            organization_name.len() <= VerificationApplication::MAX_NAME_LEN
+                && document_uri.len() <= VerificationApplication::MAX_URI_LEN,
+            CredVaultError::FieldTooLong
+        );
+
+        let issuer_account = &mut ctx.accounts.issuer_account;
+        require!(
+            issuer_account.status == IssuerStatus::Pending,
+            CredVaultError::InvalidIssuerTransition
+        );
+        require!(
+            !issuer_account.has_open_application,
+            CredVaultError::ApplicationAlreadyOpen
+        );
+
+        if let Some(fee) = fee {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
         }
-        
-        if let Some(github) = new_github_link {
-            proof_of_work_account.github_link = github;
+
+        // The vault is created on the first application and reused by later ones
+        let issuer_stake = &mut ctx.accounts.issuer_stake;
+        if issuer_stake.issuer_account == Pubkey::default() {
+            issuer_stake.issuer_account = issuer_account.key();
+            issuer_stake.bump = ctx.bumps.issuer_stake;
         }
-        
-        if let Some(demo) = new_demo_link {
-            proof_of_work_account.demo_link = demo;
+        if stake > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: issuer_stake.to_account_info(),
+                    },
+                ),
+                stake,
+            )?;
+            issuer_stake.amount = issuer_stake
+                .amount
+                .checked_add(stake)
+                .ok_or(CredVaultError::ArithmeticOverflow)?;
         }
+        require!(
+            issuer_stake.amount >= MIN_ISSUER_STAKE,
+            CredVaultError::InsufficientStake
+        );
 
-        // Update metadata on-chain as well using metaplex
-        let token_metadata = &mut ctx.accounts.token_metadata;
-        let update_authority = &ctx.accounts.student;
+        let application = &mut ctx.accounts.application;
+        application.issuer_account = issuer_account.key();
+        application.id = issuer_account.application_count;
+        application.organization_name = organization_name;
+        application.document_uri = document_uri;
+        application.document_hash = document_hash;
+        application.fee_paid = fee;
+        application.stake = issuer_stake.amount;
+        application.status = ApplicationStatus::Pending;
+        application.reason_code = None;
+        application.decided_by = None;
+        application.proposal_id = None;
+        application.submitted_at = Clock::get()?.unix_timestamp;
+        application.decided_at = None;
+        application.bump = ctx.bumps.application;
 
-        UpdateMetadataAccountsV2Cpi {
-            metadata: token_metadata.to_account_info().into(),
-            update_authority: update_authority.to_account_info().into(),
-            system_program: ctx.accounts.system_program.to_account_info().into(),
-        }
-        .invoke(&UpdateMetadataAccountsV2InstructionArgs {
-            new_update_authority: None,
-            data: Some(anchor_lang::solana_program::program_pack::Pack::pack(
-                &mpl_token_metadata::state::DataV2 {
-                    name: proof_of_work_account.project_title.clone(),
-                    symbol: "POW".to_string(),
-                    uri: format!("https://arweave.net/{}", generate_proof_of_work_metadata_uri(&proof_of_work_account.project_title, proof_of_work_account.student_pubkey)),
-                    seller_fee_basis_points: 500,
-                    creators: None,
-                    collection: None,
-                    uses: None,
+        issuer_account.application_count = issuer_account
+            .application_count
+            .checked_add(1)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
+        issuer_account.has_open_application = true;
+
+        msg!("Verification application {} submitted for issuer {}", application.id, issuer_account.issuer_pubkey);
+        Ok(())
+    }
+
+    // Suspend an issuer pending investigation (any single admin, so it can act quickly).
+    // `effective_at` may be backdated; credentials created from then on stop verifying.
+    pub fn suspend_issuer(ctx: Context<SuspendIssuer>, effective_at: Option<i64>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let effective_at = effective_at.unwrap_or(now);
+        require!(effective_at <= now, CredVaultError::InvalidSuspensionTime);
+
+        let issuer_account = &mut ctx.accounts.issuer_account;
+        set_issuer_status(issuer_account, IssuerStatus::Suspended, now, effective_at)?;
+
+        msg!("Issuer {} suspended from {}", issuer_account.issuer_pubkey, effective_at);
+        Ok(())
+    }
+
+    // Verify a pending issuer as a sub-issuer (verified parent's authority only), e.g. an
+    // accreditation body verifying a university. `path_len` limits how many further levels
+    // of sub-issuers the child may accredit and must be below the parent's own.
+    pub fn accredit_issuer(ctx: Context<AccreditIssuer>, path_len: u8) -> Result<()> {
+        let parent = &ctx.accounts.parent_account;
+        let child = &mut ctx.accounts.child_account;
+
+        require!(
+            child.key() != parent.key()
+                && child.parent.map_or(true, |current| current == parent.issuer_pubkey),
+            CredVaultError::NotParentIssuer
+        );
+        // Issuers verified by the admins, or already accredited, can't be taken over
+        require!(child.status == IssuerStatus::Pending, CredVaultError::IssuerNotPending);
+        require!(
+            path_len < parent.path_len && parent.depth < MAX_ISSUER_DEPTH,
+            CredVaultError::AccreditationDepthExceeded
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require_current_verification(parent, now)?;
+        set_issuer_status(child, IssuerStatus::Verified, now, now)?;
+        child.parent = Some(parent.issuer_pubkey);
+        child.depth = parent.depth + 1;
+        child.path_len = path_len;
+
+        msg!("Issuer {} accredited by {} at depth {}", child.issuer_pubkey, parent.issuer_pubkey, child.depth);
+        Ok(())
+    }
+
+    // Change a sub-issuer's status, e.g. to suspend or revoke it (parent's authority only)
+    pub fn set_sub_issuer_status(ctx: Context<SetSubIssuerStatus>, status: IssuerStatus) -> Result<()> {
+        let parent_pubkey = ctx.accounts.parent_account.issuer_pubkey;
+        let child = &mut ctx.accounts.child_account;
+        require!(
+            child.parent == Some(parent_pubkey),
+            CredVaultError::NotParentIssuer
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        set_issuer_status(child, status, now, now)?;
+
+        msg!("Sub-issuer {} set to {:?} by {}", child.issuer_pubkey, status, parent_pubkey);
+        Ok(())
+    }
+
+    // Propagate a revocation one level down the accreditation hierarchy (permissionless).
+    // Until this runs, verify_credential already reports the child's credentials as invalid.
+    pub fn cascade_issuer_revocation(ctx: Context<CascadeIssuerRevocation>) -> Result<()> {
+        let parent_pubkey = ctx.accounts.parent_account.issuer_pubkey;
+        let child = &mut ctx.accounts.child_account;
+        require!(
+            child.parent == Some(parent_pubkey),
+            CredVaultError::NotParentIssuer
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        set_issuer_status(child, IssuerStatus::Revoked, now, now)?;
+
+        msg!("Issuer {} revoked with its parent {}", child.issuer_pubkey, parent_pubkey);
+        Ok(())
+    }
+
+    // Add lamports to an issuer's bonded stake (issuer only)
+    pub fn deposit_stake(ctx: Context<DepositStake>, amount: u64) -> Result<()> {
+        require!(amount > 0, CredVaultError::InsufficientStake);
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.issuer_stake.to_account_info(),
                 },
-            )?),
-            primary_sale_happened: None,
-            is_mutable: true, // Allow updates
-        })?;
+            ),
+            amount,
+        )?;
 
-        msg!("Proof of Work metadata updated successfully!");
+        let issuer_stake = &mut ctx.accounts.issuer_stake;
+        issuer_stake.amount = issuer_stake
+            .amount
+            .checked_add(amount)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
+
+        msg!("Issuer {} stake is now {}", ctx.accounts.issuer_account.issuer_pubkey, issuer_stake.amount);
         Ok(())
     }
 
-    // Revoke a credential (issuer only)
-    pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
-        let credential_account = &mut ctx.accounts.credential_account;
+    // Start unbonding part of the stake (issuer only). It can be withdrawn UNBONDING_PERIOD
+    // later; each request restarts the delay for everything still unbonding. A verified or
+    // applying issuer must keep MIN_ISSUER_STAKE bonded.
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        let issuer_account = &ctx.accounts.issuer_account;
+        let issuer_stake = &mut ctx.accounts.issuer_stake;
+        require!(issuer_stake.pending_cases == 0, CredVaultError::PendingStakeCase);
 
-        // Verify this is called by the original issuer
+        // A suspended or reported issuer may yet be slashed, so nothing unbonds until it is cleared
         require!(
-            ctx.accounts.issuer.key() == credential_account.issuer_pubkey,
-            CredVaultError::UnauthorizedIssuer
+            issuer_account.status != IssuerStatus::Suspended && issuer_account.open_reports == 0,
+            CredVaultError::StakeUnderInvestigation
         );
 
-        // Mark as revoked
-        credential_account.is_revoked = true;
-        credential_account.revoked_at = Some(Clock::get()?.unix_timestamp);
+        let remaining = issuer_stake
+            .amount
+            .checked_sub(amount)
+            .ok_or(CredVaultError::InsufficientStake)?;
+        let must_stay_bonded = issuer_account.status == IssuerStatus::Verified
+            || issuer_account.has_open_application;
+        require!(
+            amount > 0 && (!must_stay_bonded || remaining >= MIN_ISSUER_STAKE),
+            CredVaultError::InsufficientStake
+        );
+
+        let available_at = Clock::get()?.unix_timestamp + UNBONDING_PERIOD;
+        issuer_stake.amount = remaining;
+        issuer_stake.unbonding_amount = issuer_stake
+            .unbonding_amount
+            .checked_add(amount)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
+        issuer_stake.unbonding_available_at = Some(available_at);
 
-        msg!("Credential revoked successfully!");
+        msg!("Issuer {} unbonding {} until {}", issuer_account.issuer_pubkey, amount, available_at);
         Ok(())
     }
-}
 
-// Helper function to generate metadata URI for proof of work
-fn generate_proof_of_work_metadata_uri(title: &str, student_pubkey: Pubkey) -> String {
-    // In a real implementation, this would create a proper IPFS or Arweave hash
-    // For this example, we'll just return a placeholder
-    format!("{}-{}", title.replace(" ", "-"), student_pubkey.to_string()[..8].to_lowercase())
-}
+    // Withdraw unbonded stake to the issuer's authority once the delay has passed and
+    // no stake case is pending (issuer only)
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let issuer_stake = &mut ctx.accounts.issuer_stake;
+        let amount = issuer_stake.unbonding_amount;
+        require!(amount > 0, CredVaultError::NothingToWithdraw);
+        require!(issuer_stake.pending_cases == 0, CredVaultError::PendingStakeCase);
+        require!(
+            issuer_stake.unbonding_available_at.map_or(false, |available_at| now >= available_at),
+            CredVaultError::StakeUnbonding
+        );
 
-// Account definitions
-#[account]
-pub struct IssuerAccount {
-    pub issuer_pubkey: Pubkey,
-    pub bump: u8,
-    pub is_verified: bool,
-    pub created_at: i64,
-}
+        // The vault is program-owned, so lamports are moved directly
+        **issuer_stake.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+        issuer_stake.unbonding_amount = 0;
+        issuer_stake.unbonding_available_at = None;
 
-#[account]
-pub struct CredentialAccount {
-    pub issuer_pubkey: Pubkey,
-    pub student_pubkey: Pubkey,
-    pub skill_name: String,
-    pub issue_date: i64,
-    pub credential_uri: String,
-    pub is_soulbound: bool, // Non-transferable flag
-    pub is_revoked: bool,
-    pub created_at: i64,
-    pub revoked_at: Option<i64>,
-    pub bump: u8,
-}
+        msg!("Issuer {} withdrew {} of stake", ctx.accounts.issuer_account.issuer_pubkey, amount);
+        Ok(())
+    }
 
-#[account]
-pub struct ProofOfWorkAccount {
-    pub student_pubkey: Pubkey,
-    pub project_title: String,
-    pub project_description: String,
-    pub github_link: String,
-    pub demo_link: String,
-    pub timestamp: i64,
-    pub is_transferable: bool, // Unlike SBTs, these can be transferred
-    pub created_at: i64,
-    pub bump: u8,
+    // Open a fraud case against an issuer's stake, freezing unbonding and withdrawals until
+    // it is dismissed or resolved by a slash (any single admin, so it can act quickly)
+    pub fn open_stake_case(ctx: Context<ResolveStakeCase>) -> Result<()> {
+        let issuer_stake = &mut ctx.accounts.issuer_stake;
+        issuer_stake.pending_cases = issuer_stake
+            .pending_cases
+            .checked_add(1)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
+
+        msg!("Stake case opened against {} ({} pending)", issuer_stake.issuer_account, issuer_stake.pending_cases);
+        Ok(())
+    }
+
+    // Close a stake case without slashing (any single admin)
+    pub fn dismiss_stake_case(ctx: Context<ResolveStakeCase>) -> Result<()> {
+        let issuer_stake = &mut ctx.accounts.issuer_stake;
+        require!(issuer_stake.pending_cases > 0, CredVaultError::NoPendingStakeCase);
+        issuer_stake.pending_cases -= 1;
+
+        msg!("Stake case against {} dismissed ({} pending)", issuer_stake.issuer_account, issuer_stake.pending_cases);
+        Ok(())
+    }
+
+    // Report a forged or misissued credential, or a fraudulent issuer when no credential is
+    // passed (anyone). The optional bond is held in the report until it is resolved.
+    pub fn report_credential(
+        ctx: Context<ReportCredential>,
+        evidence_uri: String,
+        evidence_hash: [u8; 32],
+        bond: Option<u64>,
+    ) -> Result<()> {
+        require!(evidence_uri.len() <= FraudReport::MAX_URI_LEN, CredVaultError::FieldTooLong);
+
+        if let Some(bond) = bond {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.reporter.to_account_info(),
+                        to: ctx.accounts.fraud_report.to_account_info(),
+                    },
+                ),
+                bond,
+            )?;
+        }
+
+        // Open reports are counted on their target so verification can show them
+        let issuer_account = &mut ctx.accounts.issuer_account;
+        let credential = match ctx.accounts.credential_account.as_mut() {
+            Some(credential_account) => {
+                credential_account.open_reports = credential_account
+                    .open_reports
+                    .checked_add(1)
+                    .ok_or(CredVaultError::ArithmeticOverflow)?;
+                Some(credential_account.key())
+            }
+            None => {
+                issuer_account.open_reports = issuer_account
+                    .open_reports
+                    .checked_add(1)
+                    .ok_or(CredVaultError::ArithmeticOverflow)?;
+                None
+            }
+        };
+
+        let fraud_report = &mut ctx.accounts.fraud_report;
+        fraud_report.issuer_account = issuer_account.key();
+        fraud_report.credential_account = credential;
+        fraud_report.reporter = ctx.accounts.reporter.key();
+        fraud_report.evidence_uri = evidence_uri;
+        fraud_report.evidence_hash = evidence_hash;
+        fraud_report.bond = bond.unwrap_or(0);
+        fraud_report.created_at = Clock::get()?.unix_timestamp;
+        fraud_report.bump = ctx.bumps.fraud_report;
+
+        msg!("Fraud report filed against {} by {}", fraud_report.target(), fraud_report.reporter);
+        Ok(())
+    }
+
+    // Mint a credential SBT to a student
+    pub fn mint_credential(
+        ctx: Context<MintCredential>,
+        skill_name: String,
+        issue_date: i64,
+        credential_uri: String,
+        expires_at: Option<i64>,
+        co_issuers: Vec<Pubkey>,
+    ) -> Result<()> {
+        // The token program passed in selects legacy SPL Token or Token-2022
+        let token_kind = CredentialTokenKind::from_program_id(&ctx.accounts.token_program.key())?;
+        validate_expiry(expires_at, issue_date)?;
+        validate_co_issuers(&co_issuers, &ctx.accounts.issuer.issuer_pubkey)?;
+
+        // A multisig issuer mints only through an approved operation; otherwise the
+        // issuer's authority or a delegate with mint permission may issue
+        let now = Clock::get()?.unix_timestamp;
+        require_current_verification(&ctx.accounts.issuer, now)?;
+        let issued_by = if ctx.accounts.issuer.multisig_enabled {
+            let multisig = ctx.accounts.multisig.as_ref().ok_or(CredVaultError::MultisigRequired)?;
+            let operation = ctx.accounts.operation.as_mut().ok_or(CredVaultError::MultisigRequired)?;
+            let expected = IssuerOperationKind::MintCredential {
+                student: ctx.accounts.student.key(),
+                skill_name: skill_name.clone(),
+                issue_date,
+                credential_uri: credential_uri.clone(),
+                expires_at,
+                co_issuers: co_issuers.clone(),
+            };
+            consume_issuer_operation(multisig, operation, ctx.accounts.authority.key(), &expected, now)?;
+            None
+        } else {
+            authorize_issuer_signer(
+                &ctx.accounts.issuer,
+                ctx.accounts.authority.key(),
+                ctx.accounts.delegate.as_mut(),
+                IssuerDelegate::MINT,
+                &skill_name,
+                now,
+            )?
+        };
+
+        let credential_account = &mut ctx.accounts.credential_account;
+        credential_account.issuer_pubkey = ctx.accounts.issuer.issuer_pubkey;
+        credential_account.student_pubkey = ctx.accounts.student.key();
+        credential_account.skill_name = skill_name;
+        credential_account.issue_date = issue_date;
+        credential_account.credential_uri = credential_uri;
+        credential_account.is_soulbound = true; // Mark as non-transferable
+        credential_account.is_revoked = false; // Initially not revoked
+        credential_account.created_at = now;
+        credential_account.expires_at = expires_at;
+        credential_account.issued_by = issued_by;
+        // Co-issuers countersign later; until they all have, the credential is not valid
+        credential_account.co_issuers = co_issuers
+            .into_iter()
+            .map(|issuer_pubkey| CoIssuer { issuer_pubkey, signed_at: None })
+            .collect();
+        credential_account.issuer_verified_until = ctx.accounts.issuer.verified_until;
+        credential_account.open_reports = 0;
+        credential_account.revocation_history = Vec::new();
+        credential_account.token_kind = token_kind;
+        credential_account.bump = ctx.bumps.credential_account;
+
+        // Verify the issuer is verified
+        require!(
+            ctx.accounts.issuer.status == IssuerStatus::Verified,
+            CredVaultError::IssuerNotVerified
+        );
+
+        // Create the NFT token for the credential
+        let rent_info = ctx.accounts.rent.to_account_info();
+        mint_credential_token(
+            CredentialTokenAccounts {
+                payer: &ctx.accounts.authority,
+                student: &ctx.accounts.student,
+                credential_mint: &ctx.accounts.credential_mint,
+                credential_token_account: &ctx.accounts.credential_token_account,
+                token_metadata: ctx.accounts.token_metadata.as_ref(),
+                program_authority: &ctx.accounts.program_authority,
+                metadata_program: &ctx.accounts.metadata_program,
+                token_program: &ctx.accounts.token_program,
+                associated_token_program: &ctx.accounts.associated_token_program,
+                system_program: &ctx.accounts.system_program,
+                rent: &rent_info,
+            },
+            ctx.accounts.credential_account.key(),
+            &ctx.accounts.credential_account,
+            ctx.bumps.credential_mint,
+            ctx.bumps.program_authority,
+        )?;
+
+        msg!("Credential minted successfully!");
+
+        Ok(())
+    }
+
+    // Issue the same skill to a whole cohort. `remaining_accounts` holds, per student:
+    // [student, credential_account, credential_mint, credential_token_account]
+    // followed by [token_metadata] for SPL Token credentials. Students must sign, except
+    // those that already hold this credential.
+    // Students that already hold this credential are skipped and reported back.
+    pub fn batch_mint_credentials<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchMintCredentials<'info>>,
+        skill_name: String,
+        issue_date: i64,
+        credential_uri: String,
+        expires_at: Option<i64>,
+    ) -> Result<BatchMintResult> {
+        let token_kind = CredentialTokenKind::from_program_id(&ctx.accounts.token_program.key())?;
+        validate_expiry(expires_at, issue_date)?;
+        // Multisig issuers approve each credential individually
+        require!(!ctx.accounts.issuer.multisig_enabled, CredVaultError::MultisigRequired);
+        let accounts_per_student = match token_kind {
+            CredentialTokenKind::SplToken => 5,
+            CredentialTokenKind::Token2022 => 4,
+        };
+        let remaining = ctx.remaining_accounts;
+        require!(
+            !remaining.is_empty() && remaining.len() % accounts_per_student == 0,
+            CredVaultError::InvalidBatchAccounts
+        );
+
+        let issuer_pubkey = ctx.accounts.issuer.issuer_pubkey;
+        let rent = Rent::get()?;
+        let rent_info = ctx.accounts.rent.to_account_info();
+        let now = Clock::get()?.unix_timestamp;
+        require_current_verification(&ctx.accounts.issuer, now)?;
+        let mut result = BatchMintResult {
+            minted: 0,
+            skipped: Vec::new(),
+        };
+
+        for student_accounts in remaining.chunks(accounts_per_student) {
+            let student = &student_accounts[0];
+            let credential_info = &student_accounts[1];
+
+            let (credential_key, credential_bump) = Pubkey::find_program_address(
+                &[b"credential", student.key.as_ref(), issuer_pubkey.as_ref(), skill_name.as_bytes()],
+                ctx.program_id,
+            );
+            require_keys_eq!(credential_info.key(), credential_key, CredVaultError::InvalidBatchAccounts);
+
+            // Skip students that already hold this credential
+            if credential_info.owner == ctx.program_id {
+                msg!("Skipping {}: credential already exists", student.key);
+                result.skipped.push(student.key());
+                continue;
+            }
+            // As in mint_credential, students consent to receiving a soulbound credential
+            require!(student.is_signer, CredVaultError::StudentSignatureRequired);
+
+            let (mint_key, mint_bump) = Pubkey::find_program_address(
+                &[b"mint", credential_key.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(student_accounts[2].key(), mint_key, CredVaultError::InvalidBatchAccounts);
+            require_keys_eq!(
+                student_accounts[3].key(),
+                anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                    student.key,
+                    &mint_key,
+                    &token_kind.program_id(),
+                ),
+                CredVaultError::InvalidBatchAccounts
+            );
+
+            let issued_by = authorize_issuer_signer(
+                &ctx.accounts.issuer,
+                ctx.accounts.authority.key(),
+                ctx.accounts.delegate.as_mut(),
+                IssuerDelegate::MINT,
+                &skill_name,
+                now,
+            )?;
+
+            // Create and write the credential record
+            let credential_seeds: &[&[u8]] = &[
+                b"credential",
+                student.key.as_ref(),
+                issuer_pubkey.as_ref(),
+                skill_name.as_bytes(),
+                &[credential_bump],
+            ];
+            create_pda_account(
+                &ctx.accounts.authority,
+                credential_info,
+                &ctx.accounts.system_program,
+                CredentialAccount::SPACE,
+                rent.minimum_balance(CredentialAccount::SPACE),
+                ctx.program_id,
+                credential_seeds,
+            )?;
+            let credential = CredentialAccount {
+                issuer_pubkey,
+                student_pubkey: student.key(),
+                skill_name: skill_name.clone(),
+                issue_date,
+                credential_uri: credential_uri.clone(),
+                is_soulbound: true,
+                is_revoked: false,
+                created_at: now,
+                revoked_at: None,
+                expires_at,
+                issued_by,
+                co_issuers: Vec::new(),
+                issuer_verified_until: ctx.accounts.issuer.verified_until,
+                open_reports: 0,
+                revocation_history: Vec::new(),
+                token_kind,
+                bump: credential_bump,
+            };
+            credential.try_serialize(&mut &mut credential_info.try_borrow_mut_data()?[..])?;
+
+            mint_credential_token(
+                CredentialTokenAccounts {
+                    payer: &ctx.accounts.authority,
+                    student,
+                    credential_mint: &student_accounts[2],
+                    credential_token_account: &student_accounts[3],
+                    token_metadata: student_accounts.get(4),
+                    program_authority: &ctx.accounts.program_authority,
+                    metadata_program: &ctx.accounts.metadata_program,
+                    token_program: &ctx.accounts.token_program,
+                    associated_token_program: &ctx.accounts.associated_token_program,
+                    system_program: &ctx.accounts.system_program,
+                    rent: &rent_info,
+                },
+                credential_key,
+                &credential,
+                mint_bump,
+                ctx.bumps.program_authority,
+            )?;
+            result.minted += 1;
+        }
+
+        msg!(
+            "Batch minted {} credentials, skipped {}",
+            result.minted,
+            result.skipped.len()
+        );
+        Ok(result)
+    }
+
+    // Mint a proof-of-work NFT for completed projects or hackathon work
+    pub fn mint_proof_of_work(
+        ctx: Context<MintProofOfWork>,
+        project_title: String,
+        project_description: String,
+        github_link: String,
+        demo_link: String,
+    ) -> Result<()> {
+        let proof_of_work_account = &mut ctx.accounts.proof_of_work_account;
+        proof_of_work_account.student_pubkey = ctx.accounts.student.key();
+        proof_of_work_account.project_title = project_title.clone();
+        proof_of_work_account.project_description = project_description;
+        proof_of_work_account.github_link = github_link;
+        proof_of_work_account.demo_link = demo_link;
+        proof_of_work_account.timestamp = Clock::get()?.unix_timestamp;
+        proof_of_work_account.is_transferable = true; // Unlike SBTs, these can be transferred
+        proof_of_work_account.created_at = Clock::get()?.unix_timestamp;
+        proof_of_work_account.bump = *ctx.bumps.get("proof_of_work_account").unwrap();
+
+        // Create the NFT token for the proof of work
+        let mint = &ctx.accounts.proof_of_work_mint;
+        let token_program = &ctx.accounts.token_program;
+        let metadata_program = &ctx.accounts.metadata_program;
+        let token_metadata = &mut ctx.accounts.token_metadata;
+        let payer = &ctx.accounts.student; // Student pays for and owns this NFT
+        let system_program = &ctx.accounts.system_program;
+        let rent = &ctx.accounts.rent;
+
+        // Create the metadata account
+        CreateMetadataAccountV3Cpi {
+            metadata: token_metadata.to_account_info().into(),
+            mint: mint.to_account_info().into(),
+            mint_authority: payer.to_account_info().into(),
+            payer: payer.to_account_info().into(),
+            update_authority: (payer.to_account_info().clone(), true.into()),
+            system_program: system_program.to_account_info().into(),
+            rent: rent.to_account_info().into(),
+        }
+        .invoke(&CreateMetadataAccountV3InstructionArgs {
+            data: anchor_lang::solana_program::program_pack::Pack::pack(
+                &mpl_token_metadata::state::DataV2 {
+                    name: proof_of_work_account.project_title.clone(),
+                    symbol: "POW".to_string(), // Proof of Work token
+                    uri: format!("https://arweave.net/{}", generate_proof_of_work_metadata_uri(&proof_of_work_account.project_title, proof_of_work_account.student_pubkey)), // In a real implementation, this would be an actual IPFS or Arweave link
+                    seller_fee_basis_points: 500, // 5% royalty for the creator
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                },
+            )?,
+            is_mutable: true, // Allow updates to metadata if needed
+            collection_details: None,
+        })?;
+
+        // Create the master edition account - this is what makes it a proper NFT, but it's transferable
+        let master_edition = &mut ctx.accounts.master_edition;
+        CreateMasterEditionV3Cpi {
+            edition: master_edition.to_account_info().into(),
+            mint: mint.to_account_info().into(),
+            update_authority: payer.to_account_info().into(),
+            mint_authority: payer.to_account_info().into(),
+            payer: payer.to_account_info().into(),
+            metadata: token_metadata.to_account_info().into(),
+            token_program: token_program.to_account_info().into(),
+            system_program: system_program.to_account_info().into(),
+            rent: rent.to_account_info().into(),
+        }
+        .invoke(&CreateMasterEditionV3InstructionArgs {
+            max_supply: Some(0), // This makes it a one-of-a-kind NFT, but still transferable
+        })?;
+
+        // Mint one token to the student's token account
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.proof_of_work_mint.to_account_info(),
+            to: ctx.accounts.proof_of_work_token_account.to_account_info(),
+            authority: ctx.accounts.student.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        anchor_spl::token::mint_to(cpi_ctx, 1)?;
+
+        msg!("Proof of Work NFT minted successfully for project: {}", proof_of_work_account.project_title);
+
+        Ok(())
+    }
+
+    // Create a trust registry: a list of issuers endorsed by its maintainers, which verifiers
+    // can evaluate credentials against instead of the admins' verification
+    pub fn create_trust_registry(
+        ctx: Context<CreateTrustRegistry>,
+        name: String,
+        maintainers: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(name.len() <= TrustRegistry::MAX_NAME_LEN, CredVaultError::FieldTooLong);
+        validate_maintainers(&maintainers)?;
+
+        let registry = &mut ctx.accounts.registry;
+        registry.authority = ctx.accounts.authority.key();
+        registry.name = name;
+        registry.maintainers = maintainers;
+        registry.entry_count = 0;
+        registry.created_at = Clock::get()?.unix_timestamp;
+        registry.bump = ctx.bumps.registry;
+
+        msg!("Trust registry {} created", registry.name);
+        Ok(())
+    }
+
+    // Replace a registry's maintainers (registry authority only)
+    pub fn set_registry_maintainers(
+        ctx: Context<SetRegistryMaintainers>,
+        maintainers: Vec<Pubkey>,
+    ) -> Result<()> {
+        validate_maintainers(&maintainers)?;
+        ctx.accounts.registry.maintainers = maintainers;
+
+        msg!("Trust registry {} maintainers updated", ctx.accounts.registry.name);
+        Ok(())
+    }
+
+    // Endorse an issuer in a registry (registry maintainer only)
+    pub fn add_registry_issuer(ctx: Context<AddRegistryIssuer>) -> Result<()> {
+        let entry = &mut ctx.accounts.entry;
+        entry.registry = ctx.accounts.registry.key();
+        entry.issuer_pubkey = ctx.accounts.issuer_account.issuer_pubkey;
+        entry.added_by = ctx.accounts.maintainer.key();
+        entry.added_at = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.entry;
+
+        let registry = &mut ctx.accounts.registry;
+        registry.entry_count = registry
+            .entry_count
+            .checked_add(1)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
+
+        msg!("Issuer {} added to trust registry {}", entry.issuer_pubkey, registry.name);
+        Ok(())
+    }
+
+    // Withdraw a registry's endorsement of an issuer (registry maintainer only)
+    pub fn remove_registry_issuer(ctx: Context<RemoveRegistryIssuer>) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.entry_count = registry.entry_count.saturating_sub(1);
+
+        msg!("Issuer {} removed from trust registry {}", ctx.accounts.entry.issuer_pubkey, registry.name);
+        Ok(())
+    }
+
+    // Verify a credential. The result is also readable through Anchor return data.
+    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<VerificationResult> {
+        let credential = &ctx.accounts.credential_account;
+        let issuer = &ctx.accounts.issuer;
+        let now = Clock::get()?.unix_timestamp;
+
+        // If the mint is supplied, it must be the single token issued under the recorded token program
+        let token_matches = ctx.accounts.credential_mint.as_ref().map_or(true, |mint| {
+            *mint.to_account_info().owner == credential.token_kind.program_id() && mint.supply == 1
+        });
+
+        // Against a trust registry, the issuer must be listed in it. The registry's endorsement
+        // then stands in for the admins' verification, though suspension and revocation still apply.
+        let registry_listed = match &ctx.accounts.registry {
+            Some(registry) => {
+                let entry = ctx
+                    .accounts
+                    .registry_entry
+                    .as_ref()
+                    .ok_or(CredVaultError::InvalidRegistryEntry)?;
+                let (entry_key, _) = Pubkey::find_program_address(
+                    &[b"registry-entry", registry.key().as_ref(), credential.issuer_pubkey.as_ref()],
+                    ctx.program_id,
+                );
+                require!(entry.key() == entry_key, CredVaultError::InvalidRegistryEntry);
+                Some(entry.owner == ctx.program_id && !entry.data_is_empty())
+            }
+            None => None,
+        };
+        let issuer_status = issuer_standing(issuer, credential.created_at).filter(|status| {
+            !(registry_listed == Some(true) && *status == CredentialStatus::IssuerUnverified)
+        });
+
+        // `remaining_accounts` holds the co-issuer accounts, in the order the credential lists them,
+        // followed by the issuer's accreditors from its parent up to the trusted root
+        require!(
+            ctx.remaining_accounts.len() >= credential.co_issuers.len(),
+            CredVaultError::InvalidCoIssuerAccounts
+        );
+        let (co_issuer_infos, chain_infos) = ctx.remaining_accounts.split_at(credential.co_issuers.len());
+        let (issuer_chain, accredited) =
+            walk_issuer_chain(issuer, chain_infos, ctx.program_id, credential.created_at)?;
+
+        let mut co_issuers = Vec::with_capacity(credential.co_issuers.len());
+        for (co_issuer, info) in credential.co_issuers.iter().zip(co_issuer_infos) {
+            let (issuer_key, _) = Pubkey::find_program_address(
+                &[b"issuer", co_issuer.issuer_pubkey.as_ref()],
+                ctx.program_id,
+            );
+            require!(
+                info.key() == issuer_key && info.owner == ctx.program_id,
+                CredVaultError::InvalidCoIssuerAccounts
+            );
+            let co_issuer_account = IssuerAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+            // A co-issuer's standing is judged from when it countersigned
+            let status = match co_issuer.signed_at {
+                None => CredentialStatus::AwaitingCountersignature,
+                Some(signed_at) => issuer_standing(&co_issuer_account, signed_at)
+                    .unwrap_or(CredentialStatus::Valid),
+            };
+            co_issuers.push(CoIssuerStanding {
+                issuer_pubkey: co_issuer.issuer_pubkey,
+                signed_at: co_issuer.signed_at,
+                issuer_status: co_issuer_account.status,
+                status,
+            });
+        }
+        let co_issuer_status = co_issuers
+            .iter()
+            .map(|co_issuer| co_issuer.status)
+            .find(|status| *status != CredentialStatus::Valid);
+
+        let status = if credential.is_revoked {
+            CredentialStatus::Revoked
+        } else if credential.expires_at.map_or(false, |expires_at| now >= expires_at) {
+            CredentialStatus::Expired
+        } else if registry_listed == Some(false) {
+            CredentialStatus::IssuerNotInRegistry
+        } else if let Some(issuer_status) = issuer_status {
+            issuer_status
+        } else if !accredited {
+            CredentialStatus::AccreditationInvalid
+        } else if let Some(co_issuer_status) = co_issuer_status {
+            co_issuer_status
+        } else if !token_matches {
+            CredentialStatus::TokenMismatch
+        } else {
+            CredentialStatus::Valid
+        };
+
+        msg!("Credential verification result: {:?}", status);
+        Ok(VerificationResult {
+            status,
+            issuer_pubkey: credential.issuer_pubkey,
+            issuer_verified: issuer.status == IssuerStatus::Verified
+                && issuer.verified_until.map_or(true, |verified_until| now < verified_until),
+            issuer_status: issuer.status,
+            issuer_verified_until: issuer.verified_until,
+            skill_name: credential.skill_name.clone(),
+            issue_date: credential.issue_date,
+            revoked_at: credential.revoked_at,
+            expires_at: credential.expires_at,
+            co_issuers,
+            issuer_chain,
+            registry: ctx.accounts.registry.as_ref().map(|registry| registry.key()),
+            issuer_name: ctx
+                .accounts
+                .issuer_name
+                .as_ref()
+                .filter(|issuer_name| issuer_name.approved)
+                .map(|issuer_name| issuer_name.name.clone()),
+            issuer_stake: ctx.accounts.issuer_stake.as_ref().map(|issuer_stake| issuer_stake.amount),
+            open_reports: credential.open_reports,
+            issuer_open_reports: issuer.open_reports,
+            revocation: credential.revocation().cloned(),
+            appeal_pending: credential.appeal_pending(),
+        })
+    }
+
+    // Verify a proof-of-work NFT
+    pub fn verify_proof_of_work(ctx: Context<VerifyProofOfWork>) -> Result<bool> {
+        let proof_of_work = &ctx.accounts.proof_of_work_account;
+        
+        // Check if proof of work exists and is valid
+        let is_valid = proof_of_work.created_at > 0;
+        
+        msg!("Proof of Work verification result: {}", is_valid);
+        Ok(is_valid)
+    }
+
+    // Update credential metadata (issuer only)
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        new_skill_name: Option<String>,
+        new_credential_uri: Option<String>,
+    ) -> Result<()> {
+        // Verify this is called by the original issuer's current key or a delegate
+        // allowed to update both the current and the new skill
+        let now = Clock::get()?.unix_timestamp;
+        for skill_name in [Some(&ctx.accounts.credential_account.skill_name), new_skill_name.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            authorize_issuer_signer(
+                &ctx.accounts.issuer_account,
+                ctx.accounts.issuer.key(),
+                ctx.accounts.delegate.as_mut(),
+                IssuerDelegate::UPDATE_METADATA,
+                skill_name,
+                now,
+            )?;
+        }
+
+        let credential_account = &mut ctx.accounts.credential_account;
+
+        // Token-2022 credentials have no Metaplex metadata account to update
+        require!(
+            credential_account.token_kind == CredentialTokenKind::SplToken,
+            CredVaultError::UnsupportedTokenKind
+        );
+
+        // Update fields if provided
+        if let Some(skill_name) = new_skill_name {
+            credential_account.skill_name = skill_name;
+        }
+        
+        if let Some(credential_uri) = new_credential_uri {
+            credential_account.credential_uri = credential_uri;
+        }
+
+        // Update metadata on-chain as well using metaplex, signed by the program PDA
+        let authority_seeds: &[&[u8]] = &[b"authority", &[ctx.bumps.program_authority]];
+        UpdateMetadataAccountV2Cpi::new(
+            &ctx.accounts.metadata_program,
+            UpdateMetadataAccountV2CpiAccounts {
+                metadata: &ctx.accounts.token_metadata,
+                update_authority: &ctx.accounts.program_authority,
+            },
+            UpdateMetadataAccountV2InstructionArgs {
+                data: Some(DataV2 {
+                    name: credential_account.skill_name.clone(),
+                    symbol: "CRED".to_string(),
+                    uri: credential_account.credential_uri.clone(),
+                    seller_fee_basis_points: 0,
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                }),
+                new_update_authority: None,
+                primary_sale_happened: None,
+                is_mutable: None,
+            },
+        )
+        .invoke_signed(&[authority_seeds])?;
+
+        msg!("Metadata updated successfully!");
+        Ok(())
+    }
+
+    // Update proof-of-work metadata (student only)
+    pub fn update_proof_of_work_metadata(
+        ctx: Context<UpdateProofOfWorkMetadata>,
+        new_project_title: Option<String>,
+        new_project_description: Option<String>,
+        new_github_link: Option<String>,
+        new_demo_link: Option<String>,
+    ) -> Result<()> {
+        let proof_of_work_account = &mut ctx.accounts.proof_of_work_account;
+
+        // Verify this is called by the original owner
+        require!(
+            ctx.accounts.student.key() == proof_of_work_account.student_pubkey,
+            CredVaultError::UnauthorizedUpdate
+        );
+
+        // Update fields if provided
+        if let Some(title) = new_project_title {
+            proof_of_work_account.project_title = title;
+        }
+        
+        if let Some(description) = new_project_description {
+            proof_of_work_account.project_description = description;
+        }
+        
+        if let Some(github) = new_github_link {
+            proof_of_work_account.github_link = github;
+        }
+        
+        if let Some(demo) = new_demo_link {
+            proof_of_work_account.demo_link = demo;
+        }
+
+        // Update metadata on-chain as well using metaplex
+        let token_metadata = &mut ctx.accounts.token_metadata;
+        let update_authority = &ctx.accounts.student;
+
+        UpdateMetadataAccountsV2Cpi {
+            metadata: token_metadata.to_account_info().into(),
+            update_authority: update_authority.to_account_info().into(),
+            system_program: ctx.accounts.system_program.to_account_info().into(),
+        }
+        .invoke(&UpdateMetadataAccountsV2InstructionArgs {
+            new_update_authority: None,
+            data: Some(anchor_lang::solana_program::program_pack::Pack::pack(
+                &mpl_token_metadata::state::DataV2 {
+                    name: proof_of_work_account.project_title.clone(),
+                    symbol: "POW".to_string(),
+                    uri: format!("https://arweave.net/{}", generate_proof_of_work_metadata_uri(&proof_of_work_account.project_title, proof_of_work_account.student_pubkey)),
+                    seller_fee_basis_points: 500,
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                },
+            )?),
+            primary_sale_happened: None,
+            is_mutable: true, // Allow updates
+        })?;
+
+        msg!("Proof of Work metadata updated successfully!");
+        Ok(())
+    }
+
+    // Countersign a co-issued credential (a listed co-issuer only). Like minting, this needs the
+    // co-issuer's authority, a delegate with mint permission, or an approved multisig operation.
+    pub fn countersign_credential(ctx: Context<CountersignCredential>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require_current_verification(&ctx.accounts.issuer_account, now)?;
+        if ctx.accounts.issuer_account.multisig_enabled {
+            let multisig = ctx.accounts.multisig.as_ref().ok_or(CredVaultError::MultisigRequired)?;
+            let operation = ctx.accounts.operation.as_mut().ok_or(CredVaultError::MultisigRequired)?;
+            let expected = IssuerOperationKind::CountersignCredential {
+                credential: ctx.accounts.credential_account.key(),
+            };
+            consume_issuer_operation(multisig, operation, ctx.accounts.authority.key(), &expected, now)?;
+        } else {
+            authorize_issuer_signer(
+                &ctx.accounts.issuer_account,
+                ctx.accounts.authority.key(),
+                ctx.accounts.delegate.as_mut(),
+                IssuerDelegate::MINT,
+                &ctx.accounts.credential_account.skill_name,
+                now,
+            )?;
+        }
+
+        let issuer_pubkey = ctx.accounts.issuer_account.issuer_pubkey;
+        let credential_account = &mut ctx.accounts.credential_account;
+        require!(!credential_account.is_revoked, CredVaultError::CredentialRevoked);
+
+        let co_issuer = credential_account
+            .co_issuers
+            .iter_mut()
+            .find(|co_issuer| co_issuer.issuer_pubkey == issuer_pubkey)
+            .ok_or(CredVaultError::NotACoIssuer)?;
+        require!(co_issuer.signed_at.is_none(), CredVaultError::AlreadyCountersigned);
+        co_issuer.signed_at = Some(now);
+
+        msg!("Credential countersigned by co-issuer {}", issuer_pubkey);
+        Ok(())
+    }
+
+    // Revoke a credential with a reason (the issuer or any of its co-issuers). Reason codes are
+    // assigned off-chain; `reason_uri` points to the explanation given to the student.
+    pub fn revoke_credential(
+        ctx: Context<RevokeCredential>,
+        reason_code: u16,
+        reason_uri: String,
+    ) -> Result<()> {
+        require!(reason_uri.len() <= RevocationEvent::MAX_URI_LEN, CredVaultError::FieldTooLong);
+        require!(!ctx.accounts.credential_account.is_revoked, CredVaultError::CredentialRevoked);
+
+        // A multisig issuer revokes only through an approved operation; otherwise verify this is
+        // called by the original issuer's current key or a delegate with revoke permission
+        let now = Clock::get()?.unix_timestamp;
+        if ctx.accounts.issuer_account.multisig_enabled {
+            let multisig = ctx.accounts.multisig.as_ref().ok_or(CredVaultError::MultisigRequired)?;
+            let operation = ctx.accounts.operation.as_mut().ok_or(CredVaultError::MultisigRequired)?;
+            let expected = IssuerOperationKind::RevokeCredential {
+                credential: ctx.accounts.credential_account.key(),
+                reason_code,
+                reason_uri: reason_uri.clone(),
+            };
+            consume_issuer_operation(multisig, operation, ctx.accounts.issuer.key(), &expected, now)?;
+        } else {
+            authorize_issuer_signer(
+                &ctx.accounts.issuer_account,
+                ctx.accounts.issuer.key(),
+                ctx.accounts.delegate.as_mut(),
+                IssuerDelegate::REVOKE,
+                &ctx.accounts.credential_account.skill_name,
+                now,
+            )?;
+        }
+
+        let credential_account = &mut ctx.accounts.credential_account;
+
+        // Mark as revoked
+        credential_account.is_revoked = true;
+        credential_account.revoked_at = Some(now);
+        record_revocation_event(
+            credential_account,
+            RevocationEvent {
+                action: RevocationAction::Revoked,
+                reason_code: Some(reason_code),
+                uri: Some(reason_uri),
+                actor: ctx.accounts.issuer.key(),
+                at: now,
+            },
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+
+        msg!("Credential revoked by issuer {} with reason {}", ctx.accounts.issuer_account.issuer_pubkey, reason_code);
+        Ok(())
+    }
+
+    // Appeal a revoked credential (the credential's student, once per revocation)
+    pub fn file_appeal(ctx: Context<FileAppeal>, statement_uri: String) -> Result<()> {
+        require!(statement_uri.len() <= RevocationEvent::MAX_URI_LEN, CredVaultError::FieldTooLong);
+        require!(ctx.accounts.credential_account.can_appeal(), CredVaultError::AppealNotAllowed);
+
+        let now = Clock::get()?.unix_timestamp;
+        let appeal = &mut ctx.accounts.appeal;
+        appeal.credential_account = ctx.accounts.credential_account.key();
+        appeal.student = ctx.accounts.student.key();
+        appeal.statement_uri = statement_uri.clone();
+        appeal.filed_at = now;
+        appeal.bump = ctx.bumps.appeal;
+
+        record_revocation_event(
+            &mut ctx.accounts.credential_account,
+            RevocationEvent {
+                action: RevocationAction::AppealFiled,
+                reason_code: None,
+                uri: Some(statement_uri),
+                actor: ctx.accounts.student.key(),
+                at: now,
+            },
+            &ctx.accounts.student,
+            &ctx.accounts.system_program,
+        )?;
+
+        msg!("Appeal filed against revocation of {}", appeal.credential_account);
+        Ok(())
+    }
+
+    // Extend or clear a credential's expiry without re-minting (issuer only)
+    pub fn renew_credential(ctx: Context<RenewCredential>, new_expires_at: Option<i64>) -> Result<()> {
+        require!(!ctx.accounts.credential_account.is_revoked, CredVaultError::CredentialRevoked);
+        let now = Clock::get()?.unix_timestamp;
+        require_current_verification(&ctx.accounts.issuer_account, now)?;
+
+        // Renewing re-issues the credential, so it is authorized like minting: through an
+        // approved operation for multisig issuers, else the issuer's key or a mint delegate
+        if ctx.accounts.issuer_account.multisig_enabled {
+            let multisig = ctx.accounts.multisig.as_ref().ok_or(CredVaultError::MultisigRequired)?;
+            let operation = ctx.accounts.operation.as_mut().ok_or(CredVaultError::MultisigRequired)?;
+            let expected = IssuerOperationKind::RenewCredential {
+                credential: ctx.accounts.credential_account.key(),
+                new_expires_at,
+            };
+            consume_issuer_operation(multisig, operation, ctx.accounts.issuer.key(), &expected, now)?;
+        } else {
+            authorize_issuer_signer(
+                &ctx.accounts.issuer_account,
+                ctx.accounts.issuer.key(),
+                ctx.accounts.delegate.as_mut(),
+                IssuerDelegate::MINT,
+                &ctx.accounts.credential_account.skill_name,
+                now,
+            )?;
+        }
+
+        let credential_account = &mut ctx.accounts.credential_account;
+
+        // Renewal can only extend validity; `None` makes the credential permanent
+        match (credential_account.expires_at, new_expires_at) {
+            (_, None) => {}
+            (None, Some(_)) => return err!(CredVaultError::InvalidExpiry),
+            (Some(current), Some(new_expires_at)) => require!(
+                new_expires_at > current && new_expires_at > now,
+                CredVaultError::InvalidExpiry
+            ),
+        }
+
+        credential_account.expires_at = new_expires_at;
+
+        msg!("Credential renewed until {:?}", new_expires_at);
+        Ok(())
+    }
+
+    // Set up a concurrent Merkle tree for an issuer's compressed credentials.
+    // The tree account must already be allocated by the client and owned by the compression program.
+    pub fn create_credential_tree(
+        ctx: Context<CreateCredentialTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        let tree_config = &mut ctx.accounts.tree_config;
+        tree_config.issuer_pubkey = ctx.accounts.issuer.issuer_pubkey;
+        tree_config.merkle_tree = ctx.accounts.merkle_tree.key();
+        tree_config.max_depth = max_depth;
+        tree_config.max_buffer_size = max_buffer_size;
+        tree_config.num_minted = 0;
+        tree_config.bump = ctx.bumps.tree_config;
+
+        // The tree config PDA is the tree authority, so only this program can write leaves
+        let merkle_tree_key = ctx.accounts.merkle_tree.key();
+        let tree_seeds: &[&[u8]] = &[b"credential-tree", merkle_tree_key.as_ref(), &[ctx.bumps.tree_config]];
+        invoke_account_compression(
+            INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR,
+            (max_depth, max_buffer_size),
+            modify_tree_accounts(
+                &ctx.accounts.merkle_tree.to_account_info(),
+                &ctx.accounts.tree_config.to_account_info(),
+                &ctx.accounts.log_wrapper.to_account_info(),
+                &[],
+            ),
+            &ctx.accounts.compression_program.to_account_info(),
+            &[tree_seeds],
+        )?;
+
+        msg!("Credential tree created: {}", merkle_tree_key);
+        Ok(())
+    }
+
+    // Mint a compressed credential as a leaf in the issuer's Merkle tree (no accounts or token per student)
+    pub fn mint_compressed_credential(
+        ctx: Context<MintCompressedCredential>,
+        student: Pubkey,
+        skill_name: String,
+        issue_date: i64,
+        credential_uri: String,
+    ) -> Result<()> {
+        require!(!ctx.accounts.issuer.multisig_enabled, CredVaultError::MultisigRequired);
+        let now = Clock::get()?.unix_timestamp;
+        require_current_verification(&ctx.accounts.issuer, now)?;
+
+        let leaf = CompressedCredential {
+            issuer_pubkey: ctx.accounts.issuer.issuer_pubkey,
+            student_pubkey: student,
+            skill_name,
+            issue_date,
+            credential_uri,
+            created_at: now,
+            is_revoked: false,
+            revoked_at: None,
+            leaf_index: ctx.accounts.tree_config.num_minted,
+        };
+
+        // Log the full leaf so indexers can rebuild the credential and its proof
+        log_application_data(leaf.try_to_vec()?, &ctx.accounts.log_wrapper.to_account_info())?;
+
+        let merkle_tree_key = ctx.accounts.merkle_tree.key();
+        let tree_seeds: &[&[u8]] = &[b"credential-tree", merkle_tree_key.as_ref(), &[ctx.accounts.tree_config.bump]];
+        invoke_account_compression(
+            APPEND_DISCRIMINATOR,
+            leaf.hash()?,
+            modify_tree_accounts(
+                &ctx.accounts.merkle_tree.to_account_info(),
+                &ctx.accounts.tree_config.to_account_info(),
+                &ctx.accounts.log_wrapper.to_account_info(),
+                &[],
+            ),
+            &ctx.accounts.compression_program.to_account_info(),
+            &[tree_seeds],
+        )?;
+
+        let tree_config = &mut ctx.accounts.tree_config;
+        tree_config.num_minted = tree_config
+            .num_minted
+            .checked_add(1)
+            .ok_or(CredVaultError::ArithmeticOverflow)?;
+
+        msg!("Compressed credential minted at leaf {}", leaf.leaf_index);
+        Ok(())
+    }
+
+    // Verify a compressed credential against the tree. `remaining_accounts` holds the Merkle proof.
+    pub fn verify_compressed_credential<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyCompressedCredential<'info>>,
+        root: [u8; 32],
+        leaf: CompressedCredential,
+    ) -> Result<VerificationResult> {
+        require!(
+            leaf.issuer_pubkey == ctx.accounts.tree_config.issuer_pubkey,
+            CredVaultError::InvalidIssuerAccount
+        );
+
+        // Fails the transaction if the leaf is not in the tree
+        let mut accounts = vec![AccountMeta::new_readonly(ctx.accounts.merkle_tree.key(), false)];
+        accounts.extend(
+            ctx.remaining_accounts
+                .iter()
+                .map(|node| AccountMeta::new_readonly(node.key(), false)),
+        );
+        let mut account_infos = vec![ctx.accounts.merkle_tree.to_account_info()];
+        account_infos.extend_from_slice(ctx.remaining_accounts);
+        invoke_account_compression(
+            VERIFY_LEAF_DISCRIMINATOR,
+            (root, leaf.hash()?, leaf.leaf_index),
+            (accounts, account_infos),
+            &ctx.accounts.compression_program.to_account_info(),
+            &[],
+        )?;
+
+        let issuer = &ctx.accounts.issuer;
+        let now = Clock::get()?.unix_timestamp;
+        let status = if leaf.is_revoked {
+            CredentialStatus::Revoked
+        } else if let Some(issuer_status) = issuer_standing(issuer, leaf.created_at) {
+            issuer_status
+        } else {
+            CredentialStatus::Valid
+        };
+
+        msg!("Compressed credential verification result: {:?}", status);
+        Ok(VerificationResult {
+            status,
+            issuer_pubkey: leaf.issuer_pubkey,
+            issuer_verified: issuer.status == IssuerStatus::Verified
+                && issuer.verified_until.map_or(true, |verified_until| now < verified_until),
+            issuer_status: issuer.status,
+            issuer_verified_until: issuer.verified_until,
+            skill_name: leaf.skill_name,
+            issue_date: leaf.issue_date,
+            revoked_at: leaf.revoked_at,
+            expires_at: None,
+            co_issuers: Vec::new(),
+            issuer_chain: Vec::new(),
+            registry: None,
+            issuer_name: None,
+            issuer_stake: ctx.accounts.issuer_stake.as_ref().map(|issuer_stake| issuer_stake.amount),
+            open_reports: 0,
+            issuer_open_reports: issuer.open_reports,
+            revocation: None,
+            appeal_pending: false,
+        })
+    }
+
+    // Revoke a compressed credential by replacing its leaf (issuer only). `remaining_accounts` holds the Merkle proof.
+    pub fn revoke_compressed_credential<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeCompressedCredential<'info>>,
+        root: [u8; 32],
+        leaf: CompressedCredential,
+    ) -> Result<()> {
+        // Verify the leaf belongs to this tree's issuer
+        require!(
+            leaf.issuer_pubkey == ctx.accounts.tree_config.issuer_pubkey,
+            CredVaultError::UnauthorizedIssuer
+        );
+        require!(!ctx.accounts.issuer_account.multisig_enabled, CredVaultError::MultisigRequired);
+        require!(!leaf.is_revoked, CredVaultError::CredentialRevoked);
+
+        let previous_leaf = leaf.hash()?;
+        let revoked_leaf = CompressedCredential {
+            is_revoked: true,
+            revoked_at: Some(Clock::get()?.unix_timestamp),
+            ..leaf
+        };
+        log_application_data(revoked_leaf.try_to_vec()?, &ctx.accounts.log_wrapper.to_account_info())?;
+
+        let merkle_tree_key = ctx.accounts.merkle_tree.key();
+        let tree_seeds: &[&[u8]] = &[b"credential-tree", merkle_tree_key.as_ref(), &[ctx.accounts.tree_config.bump]];
+        invoke_account_compression(
+            REPLACE_LEAF_DISCRIMINATOR,
+            (root, previous_leaf, revoked_leaf.hash()?, revoked_leaf.leaf_index),
+            modify_tree_accounts(
+                &ctx.accounts.merkle_tree.to_account_info(),
+                &ctx.accounts.tree_config.to_account_info(),
+                &ctx.accounts.log_wrapper.to_account_info(),
+                ctx.remaining_accounts,
+            ),
+            &ctx.accounts.compression_program.to_account_info(),
+            &[tree_seeds],
+        )?;
+
+        msg!("Compressed credential at leaf {} revoked", revoked_leaf.leaf_index);
+        Ok(())
+    }
+}
+
+// Helper function to generate metadata URI for proof of work
+fn generate_proof_of_work_metadata_uri(title: &str, student_pubkey: Pubkey) -> String {
+    // In a real implementation, this would create a proper IPFS or Arweave hash
+    // For this example, we'll just return a placeholder
+    format!("{}-{}", title.replace(" ", "-"), student_pubkey.to_string()[..8].to_lowercase())
+}
+
+// Accounts needed to create and mint a single credential token
+struct CredentialTokenAccounts<'a, 'info> {
+    payer: &'a AccountInfo<'info>,
+    student: &'a AccountInfo<'info>,
+    credential_mint: &'a AccountInfo<'info>,
+    credential_token_account: &'a AccountInfo<'info>,
+    token_metadata: Option<&'a AccountInfo<'info>>,
+    program_authority: &'a AccountInfo<'info>,
+    metadata_program: &'a AccountInfo<'info>,
+    token_program: &'a AccountInfo<'info>,
+    associated_token_program: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    rent: &'a AccountInfo<'info>,
+}
+
+// Create the credential mint, its metadata and the student's token account, then
+// mint the single soulbound token. Shared by mint_credential and batch_mint_credentials.
+fn mint_credential_token(
+    accounts: CredentialTokenAccounts,
+    credential_key: Pubkey,
+    credential: &CredentialAccount,
+    mint_bump: u8,
+    authority_bump: u8,
+) -> Result<()> {
+    let CredentialTokenAccounts {
+        payer,
+        student,
+        credential_mint: mint,
+        credential_token_account,
+        token_metadata,
+        program_authority,
+        metadata_program,
+        token_program,
+        associated_token_program,
+        system_program,
+        rent: rent_info,
+    } = accounts;
+    let token_kind = credential.token_kind;
+    let name = credential.skill_name.clone();
+    let symbol = "CRED".to_string();
+    let uri = credential.credential_uri.clone();
+    let rent = Rent::get()?;
+
+    // The program PDA is mint, freeze and update authority, so credential state
+    // can only change through this program's instructions
+    let mint_seeds: &[&[u8]] = &[b"mint", credential_key.as_ref(), &[mint_bump]];
+    let authority_seeds: &[&[u8]] = &[b"authority", &[authority_bump]];
+    let signer_seeds = &[authority_seeds];
+
+    // Token-2022 mints carry NonTransferable, and point at their own embedded metadata
+    let (mint_space, metadata_space) = match token_kind {
+        CredentialTokenKind::SplToken => (anchor_spl::token::Mint::LEN, 0),
+        CredentialTokenKind::Token2022 => (
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+                ExtensionType::NonTransferable,
+                ExtensionType::MetadataPointer,
+            ])?,
+            token_metadata_space(&name, &symbol, &uri),
+        ),
+    };
+    create_pda_account(
+        payer,
+        mint,
+        system_program,
+        mint_space,
+        rent.minimum_balance(mint_space + metadata_space),
+        &token_program.key(),
+        mint_seeds,
+    )?;
+
+    if token_kind == CredentialTokenKind::Token2022 {
+        token_interface::non_transferable_mint_initialize(CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::NonTransferableMintInitialize {
+                token_program_id: token_program.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+        ))?;
+        token_interface::metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token_interface::MetadataPointerInitialize {
+                    token_program_id: token_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            Some(program_authority.key()),
+            Some(mint.key()),
+        )?;
+    }
+
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::InitializeMint2 {
+                mint: mint.to_account_info(),
+            },
+        ),
+        0,
+        &program_authority.key(),
+        Some(&program_authority.key()),
+    )?;
+
+    // Create the metadata account
+    match token_kind {
+        CredentialTokenKind::SplToken => {
+            let token_metadata = token_metadata.ok_or(CredVaultError::MissingMetadataAccounts)?;
+
+            CreateMetadataAccountV3Cpi::new(
+                metadata_program,
+                CreateMetadataAccountV3CpiAccounts {
+                    metadata: token_metadata,
+                    mint,
+                    mint_authority: program_authority,
+                    payer,
+                    update_authority: (program_authority, true),
+                    system_program,
+                    rent: Some(rent_info),
+                },
+                CreateMetadataAccountV3InstructionArgs {
+                    data: DataV2 {
+                        name,
+                        symbol,
+                        uri,
+                        seller_fee_basis_points: 0, // No resale rights
+                        creators: None,
+                        collection: None,
+                        uses: None,
+                    },
+                    is_mutable: true, // Only the program PDA can update it, via update_metadata
+                    collection_details: None,
+                },
+            )
+            .invoke_signed(signer_seeds)?;
+        }
+        CredentialTokenKind::Token2022 => {
+            token_interface::token_metadata_initialize(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token_interface::TokenMetadataInitialize {
+                        token_program_id: token_program.to_account_info(),
+                        metadata: mint.to_account_info(),
+                        update_authority: program_authority.to_account_info(),
+                        mint_authority: program_authority.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                name,
+                symbol,
+                uri,
+            )?;
+        }
+    }
+
+    // Create the student's associated token account, so the credential shows up in their wallet
+    anchor_spl::associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: payer.to_account_info(),
+            associated_token: credential_token_account.to_account_info(),
+            authority: student.to_account_info(),
+            mint: mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+
+    // Mint one token to the student's token account (though it's soulbound, this creates the token)
+    let cpi_accounts = token_interface::MintTo {
+        mint: mint.to_account_info(),
+        to: credential_token_account.to_account_info(),
+        authority: program_authority.to_account_info(),
+    };
+    let cpi_program = token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token_interface::mint_to(cpi_ctx, 1)?;
+
+    // Token-2022 credentials are already non-transferable at the mint level
+    if token_kind == CredentialTokenKind::SplToken {
+        // Freeze the token account so the credential can never be transferred (Soulbound).
+        // There's deliberately no master edition: creating one hands the mint's freeze
+        // authority to the edition PDA, which Metaplex can thaw. The program PDA keeps
+        // the freeze authority instead, and no instruction thaws it.
+        let cpi_accounts = token_interface::FreezeAccount {
+            account: credential_token_account.to_account_info(),
+            mint: mint.to_account_info(),
+            authority: program_authority.to_account_info(),
+        };
+        let cpi_program = token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::freeze_account(cpi_ctx)?;
+    }
+
+    Ok(())
+}
+
+// Create a program-owned PDA account ahead of its initialization
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    lamports: u64,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+            &[seeds],
+        ),
+        lamports,
+        space as u64,
+        owner,
+    )
+}
+
+// Call an instruction of the account compression program. It is invoked directly, since
+// no spl-account-compression release builds against this program's anchor-lang version.
+fn invoke_account_compression<'info>(
+    discriminator: [u8; 8],
+    args: impl AnchorSerialize,
+    (accounts, mut account_infos): (Vec<AccountMeta>, Vec<AccountInfo<'info>>),
+    compression_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = discriminator.to_vec();
+    args.serialize(&mut data)?;
+    account_infos.push(compression_program.clone());
+    invoke_signed(
+        &Instruction {
+            program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+// Accounts of the compression program's Initialize and Modify contexts, followed by the
+// Merkle proof. The tree config PDA is the tree authority and signs the CPI.
+fn modify_tree_accounts<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    tree_authority: &AccountInfo<'info>,
+    noop: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+) -> (Vec<AccountMeta>, Vec<AccountInfo<'info>>) {
+    let mut accounts = vec![
+        AccountMeta::new(merkle_tree.key(), false),
+        AccountMeta::new_readonly(tree_authority.key(), true),
+        AccountMeta::new_readonly(noop.key(), false),
+    ];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+    let mut account_infos = vec![merkle_tree.clone(), tree_authority.clone(), noop.clone()];
+    account_infos.extend_from_slice(proof);
+    (accounts, account_infos)
+}
+
+// Log application data through the noop program, as spl-account-compression's
+// wrap_application_data_v1 does, so indexers can read leaves from transaction data
+fn log_application_data<'info>(data: Vec<u8>, noop: &AccountInfo<'info>) -> Result<()> {
+    let mut event = vec![1, 0]; // AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1)
+    data.serialize(&mut event)?;
+    invoke(
+        &Instruction {
+            program_id: NOOP_PROGRAM_ID,
+            accounts: vec![],
+            data: event,
+        },
+        &[noop.clone()],
+    )?;
+    Ok(())
+}
+
+// Check that `signer` may act for the issuer: either its authority, or a delegate holding
+// `permission` for `skill_name`. Minting consumes the delegate's quota. Returns the delegate
+// key to record on the credential, or None for the issuer's authority.
+fn authorize_issuer_signer(
+    issuer_account: &Account<IssuerAccount>,
+    signer: Pubkey,
+    delegate: Option<&mut Account<IssuerDelegate>>,
+    permission: u8,
+    skill_name: &str,
+    now: i64,
+) -> Result<Option<Pubkey>> {
+    if signer == issuer_account.authority {
+        return Ok(None);
+    }
+
+    // The delegate PDA is seeded on the issuer and signer, so it belongs to both
+    let delegate = delegate.ok_or(CredVaultError::UnauthorizedIssuer)?;
+    require!(
+        delegate.permissions & permission != 0,
+        CredVaultError::UnauthorizedIssuer
+    );
+    if let Some(expires_at) = delegate.expires_at {
+        require!(now < expires_at, CredVaultError::DelegateExpired);
+    }
+    require!(
+        delegate.allowed_skills.is_empty() || delegate.allowed_skills.iter().any(|skill| skill == skill_name),
+        CredVaultError::SkillNotDelegated
+    );
+
+    if permission == IssuerDelegate::MINT {
+        if let Some(mint_quota) = delegate.mint_quota {
+            // Start a new period once the current one has elapsed
+            if now >= delegate.period_start.saturating_add(delegate.quota_period) {
+                delegate.period_start = now;
+                delegate.minted_in_period = 0;
+            }
+            require!(
+                delegate.minted_in_period < mint_quota,
+                CredVaultError::DelegateQuotaExceeded
+            );
+            delegate.minted_in_period += 1;
+        }
+    }
+
+    Ok(Some(delegate.delegate))
+}
+
+fn validate_officers(officers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !officers.is_empty() && officers.len() <= MAX_OFFICERS,
+        CredVaultError::InvalidOfficers
+    );
+    for (i, officer) in officers.iter().enumerate() {
+        require!(!officers[..i].contains(officer), CredVaultError::InvalidOfficers);
+    }
+    require!(
+        threshold > 0 && threshold as usize <= officers.len(),
+        CredVaultError::InvalidThreshold
+    );
+    Ok(())
+}
+
+fn validate_maintainers(maintainers: &[Pubkey]) -> Result<()> {
+    require!(
+        !maintainers.is_empty() && maintainers.len() <= MAX_MAINTAINERS,
+        CredVaultError::InvalidMaintainers
+    );
+    for (i, maintainer) in maintainers.iter().enumerate() {
+        require!(!maintainers[..i].contains(maintainer), CredVaultError::InvalidMaintainers);
+    }
+    Ok(())
+}
+
+fn validate_co_issuers(co_issuers: &[Pubkey], issuer_pubkey: &Pubkey) -> Result<()> {
+    require!(co_issuers.len() <= MAX_CO_ISSUERS, CredVaultError::InvalidCoIssuers);
+    for (i, co_issuer) in co_issuers.iter().enumerate() {
+        require!(
+            co_issuer != issuer_pubkey && !co_issuers[..i].contains(co_issuer),
+            CredVaultError::InvalidCoIssuers
+        );
+    }
+    Ok(())
+}
+
+// Check that an officer is executing an approved, unexecuted operation of exactly `expected`
+// kind for this multisig, then mark it executed
+fn consume_issuer_operation(
+    multisig: &Account<IssuerMultisig>,
+    operation: &mut Account<IssuerOperation>,
+    officer: Pubkey,
+    expected: &IssuerOperationKind,
+    now: i64,
+) -> Result<()> {
+    require!(multisig.officers.contains(&officer), CredVaultError::NotAnOfficer);
+    require!(
+        operation.multisig == multisig.key() && operation.kind == *expected,
+        CredVaultError::OperationMismatch
+    );
+    require!(!operation.executed, CredVaultError::ProposalAlreadyExecuted);
+
+    // Approvals from officers that have since been removed no longer count
+    let approvals = operation
+        .approvals
+        .iter()
+        .filter(|approver| multisig.officers.contains(approver))
+        .count();
+    require!(
+        approvals >= multisig.threshold as usize,
+        CredVaultError::InsufficientApprovals
+    );
+
+    operation.executed = true;
+    operation.executed_at = Some(now);
+    Ok(())
+}
+
+// How the issuer's current standing affects a credential it created at `created_at`.
+// Credentials created before a suspension took effect stay valid.
+fn issuer_standing(issuer: &IssuerAccount, created_at: i64) -> Option<CredentialStatus> {
+    match issuer.status {
+        // Credentials from the valid window stay valid after the verification lapses
+        IssuerStatus::Verified => issuer
+            .verified_until
+            .filter(|verified_until| created_at >= *verified_until)
+            .map(|_| CredentialStatus::IssuerVerificationLapsed),
+        IssuerStatus::Pending => Some(CredentialStatus::IssuerUnverified),
+        IssuerStatus::Suspended => issuer
+            .suspended_at
+            .filter(|suspended_at| created_at >= *suspended_at)
+            .map(|_| CredentialStatus::IssuerSuspended),
+        IssuerStatus::Revoked => Some(CredentialStatus::IssuerRevoked),
+    }
+}
+
+// Walk from an issuer up to its trusted root through `accounts`, parent first. Returns the chain,
+// starting with the issuer itself, and whether every accreditor is in good standing for a
+// credential created at `created_at`.
+fn walk_issuer_chain(
+    issuer: &IssuerAccount,
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    created_at: i64,
+) -> Result<(Vec<IssuerChainLink>, bool)> {
+    require!(
+        accounts.len() == issuer.depth as usize,
+        CredVaultError::InvalidIssuerChain
+    );
+
+    let mut chain = vec![IssuerChainLink::from(issuer)];
+    let mut accredited = true;
+    let mut expected_parent = issuer.parent;
+    for info in accounts {
+        let parent_pubkey = expected_parent.ok_or(CredVaultError::InvalidIssuerChain)?;
+        let (parent_key, _) = Pubkey::find_program_address(&[b"issuer", parent_pubkey.as_ref()], program_id);
+        require!(
+            info.key() == parent_key && info.owner == program_id,
+            CredVaultError::InvalidIssuerChain
+        );
+        let parent = IssuerAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+        accredited &= issuer_standing(&parent, created_at).is_none();
+        chain.push(IssuerChainLink::from(&parent));
+        expected_parent = parent.parent;
+    }
+    // The chain must end at a root verified directly by the admins
+    require!(expected_parent.is_none(), CredVaultError::InvalidIssuerChain);
+
+    Ok((chain, accredited))
+}
+
+// Minting and accrediting need a verification that has not lapsed
+fn require_current_verification(issuer: &IssuerAccount, now: i64) -> Result<()> {
+    require!(
+        issuer.verified_until.map_or(true, |verified_until| now < verified_until),
+        CredVaultError::VerificationLapsed
+    );
+    Ok(())
+}
+
+// Append to a credential's revocation history, growing the account and topping up its rent
+fn record_revocation_event<'info>(
+    credential_account: &mut Account<'info, CredentialAccount>,
+    event: RevocationEvent,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let space = credential_account.space() + event.size();
+    let credential_info = credential_account.to_account_info();
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(credential_info.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: credential_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    credential_info.realloc(space, false)?;
+    credential_account.revocation_history.push(event);
+    Ok(())
+}
+
+// A fraud report's target: the credential if one is reported, otherwise the issuer
+fn report_target(
+    issuer_account: &Account<IssuerAccount>,
+    credential_account: &Option<Account<CredentialAccount>>,
+) -> Pubkey {
+    credential_account
+        .as_ref()
+        .map_or(issuer_account.key(), |credential_account| credential_account.key())
+}
+
+// Revoking a credential needs a report against that credential
+fn validate_report_resolution(fraud_report: &FraudReport, resolution: ReportStatus) -> Result<()> {
+    require!(
+        resolution != ReportStatus::CredentialRevoked || fraud_report.credential_account.is_some(),
+        CredVaultError::InvalidReportResolution
+    );
+    Ok(())
+}
+
+// Move an issuer to a new status, recording when the transition took effect
+fn set_issuer_status(
+    issuer: &mut IssuerAccount,
+    status: IssuerStatus,
+    now: i64,
+    effective_at: i64,
+) -> Result<()> {
+    require!(
+        issuer.status.can_transition_to(status),
+        CredVaultError::InvalidIssuerTransition
+    );
+
+    match status {
+        IssuerStatus::Pending => {
+            issuer.verified_at = None;
+            issuer.verified_until = None;
+        }
+        IssuerStatus::Verified => {
+            // Reinstating keeps the original verification date but starts a new period
+            issuer.verified_at.get_or_insert(now);
+            issuer.verified_until = Some(now + VERIFICATION_PERIOD);
+            issuer.suspended_at = None;
+        }
+        IssuerStatus::Suspended => issuer.suspended_at = Some(effective_at),
+        IssuerStatus::Revoked => issuer.revoked_at = Some(now),
+    }
+    issuer.status = status;
+    issuer.status_updated_at = now;
+    Ok(())
+}
+
+// A renewed verification must end in the future, at most one period from now
+fn validate_verification_renewal(verified_until: i64, now: i64) -> Result<()> {
+    require!(
+        verified_until > now && verified_until <= now + VERIFICATION_PERIOD,
+        CredVaultError::InvalidVerificationPeriod
+    );
+    Ok(())
+}
+
+// An expiry, if set, must fall after the issue date
+fn validate_expiry(expires_at: Option<i64>, issue_date: i64) -> Result<()> {
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > issue_date, CredVaultError::InvalidExpiry);
+    }
+    Ok(())
+}
+
+// Bytes the Token-2022 metadata extension will take once initialized:
+// TLV header + update authority + mint + name/symbol/uri + empty additional metadata
+fn token_metadata_space(name: &str, symbol: &str, uri: &str) -> usize {
+    2 + 2 + 32 + 32 + 4 + name.len() + 4 + symbol.len() + 4 + uri.len() + 4
+}
+
+// Record a new proposal, counting the proposer's approval
+fn open_proposal(
+    config: &mut Account<Config>,
+    proposal: &mut Account<Proposal>,
+    proposer: Pubkey,
+    action: ProposalAction,
+    bump: u8,
+) -> Result<()> {
+    proposal.id = config.proposal_count;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.executed_at = None;
+    proposal.bump = bump;
+
+    config.proposal_count = config
+        .proposal_count
+        .checked_add(1)
+        .ok_or(CredVaultError::ArithmeticOverflow)?;
+
+    msg!("Proposal {} created by {}", proposal.id, proposer);
+    Ok(())
+}
+
+// Apply an approved change to the admin set or threshold
+fn apply_admin_change(config: &mut Account<Config>, change: AdminChange) -> Result<()> {
+    match change {
+        AdminChange::Add { admin } => {
+            require!(!config.admins.contains(&admin), CredVaultError::DuplicateAdmin);
+            require!(config.admins.len() < MAX_ADMINS, CredVaultError::TooManyAdmins);
+            config.admins.push(admin);
+        }
+        AdminChange::Remove { admin } => {
+            let index = config
+                .admins
+                .iter()
+                .position(|existing| *existing == admin)
+                .ok_or(CredVaultError::AdminNotFound)?;
+            config.admins.remove(index);
+        }
+        AdminChange::SetThreshold { threshold } => {
+            config.threshold = threshold;
+        }
+    }
+
+    // The remaining admins must still be able to reach the threshold
+    require!(
+        config.threshold > 0 && config.threshold as usize <= config.admins.len(),
+        CredVaultError::InvalidThreshold
+    );
+    Ok(())
+}
+
+// Lamports the treasury can pay out while staying rent-exempt
+fn treasury_withdrawable(treasury: &AccountInfo) -> Result<u64> {
+    Ok(treasury.lamports().saturating_sub(Rent::get()?.minimum_balance(0)))
+}
+
+// Maximum number of governance admins
+pub const MAX_ADMINS: usize = 10;
+
+// Maximum number of officers on an issuer multisig
+pub const MAX_OFFICERS: usize = 10;
+
+// Maximum number of co-issuers on a credential, besides its issuer
+pub const MAX_CO_ISSUERS: usize = 4;
+
+// Maximum depth of the accreditation hierarchy below a root issuer
+pub const MAX_ISSUER_DEPTH: u8 = 3;
+
+// Maximum number of maintainers on a trust registry
+pub const MAX_MAINTAINERS: usize = 10;
+
+// How long an issuer's verification lasts before it must be renewed (one year)
+pub const VERIFICATION_PERIOD: i64 = 365 * 24 * 60 * 60;
+
+// Bonded stake an issuer must hold to apply for and keep its verification (0.1 SOL)
+pub const MIN_ISSUER_STAKE: u64 = 100_000_000;
+
+// Delay between requesting an unstake and being able to withdraw it (14 days)
+pub const UNBONDING_PERIOD: i64 = 14 * 24 * 60 * 60;
+
+// SPL account compression program, which holds compressed credential trees
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+// SPL noop program, used to log compressed credential leaves for indexers
+pub const NOOP_PROGRAM_ID: Pubkey = anchor_lang::solana_program::pubkey!("noopb9bkMVfRPU8a2bXc7WScTjyhXt9vENnk8YANYx");
+
+// Anchor discriminators of the compression instructions we call: sha256("global:<name>")[..8]
+pub const INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+pub const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+pub const REPLACE_LEAF_DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+pub const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+// Account definitions
+#[account]
+pub struct Config {
+    pub admins: Vec<Pubkey>,
+    pub threshold: u8, // Approvals required to execute a proposal
+    pub proposal_count: u64,
+    pub bump: u8,
+    pub created_at: i64,
+}
+
+#[account]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+    pub created_at: i64,
+    pub executed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl Proposal {
+    // discriminator + id + proposer + action + approvals + executed + created_at + executed_at + bump
+    pub const SPACE: usize = 8 + 8 + 32 + ProposalAction::SPACE + 4 + 32 * MAX_ADMINS + 1 + 8 + 9 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalAction {
+    SetIssuerStatus { issuer_account: Pubkey, status: IssuerStatus },
+    ChangeAdmins { change: AdminChange },
+    ApproveApplication { application: Pubkey, reason_code: u16 },
+    RejectApplication { application: Pubkey, reason_code: u16 },
+    ApproveProfile { issuer_account: Pubkey, profile_hash: [u8; 32] },
+    RotateIssuerAuthority { issuer_account: Pubkey, new_authority: Pubkey },
+    ApproveIssuerName { issuer_name: Pubkey },
+    RejectIssuerName { issuer_name: Pubkey },
+    SlashStake { issuer_stake: Pubkey, amount: u64, recipient: Pubkey },
+    WithdrawTreasury { amount: u64, recipient: Pubkey },
+    GrantAccreditationRights { issuer_account: Pubkey, path_len: u8 },
+    RenewVerification { issuer_account: Pubkey, verified_until: i64 },
+    ResolveFraudReport { fraud_report: Pubkey, resolution: ReportStatus, reason_code: u16 },
+    ResolveAppeal { appeal: Pubkey, overturn: bool, reason_code: u16 },
+}
+
+impl ProposalAction {
+    // Largest variant: tag + issuer_stake + amount + recipient
+    pub const SPACE: usize = 1 + 32 + 8 + 32;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum AdminChange {
+    Add { admin: Pubkey },
+    Remove { admin: Pubkey },
+    SetThreshold { threshold: u8 },
+}
+
+#[account]
+pub struct IssuerAccount {
+    pub issuer_pubkey: Pubkey, // Stable issuer ID used in PDA seeds; the original authority key
+    pub authority: Pubkey, // Current signing key
+    pub bump: u8,
+    pub status: IssuerStatus,
+    pub created_at: i64,
+    pub verified_at: Option<i64>,
+    pub verified_until: Option<i64>, // End of the current verification period
+    pub suspended_at: Option<i64>, // When the current suspension took effect
+    pub revoked_at: Option<i64>,
+    pub status_updated_at: i64,
+    pub application_count: u32, // Next verification application id
+    pub has_open_application: bool,
+    pub profile: IssuerProfile, // Approved public profile
+    pub pending_profile: Option<IssuerProfile>, // Change awaiting admin approval
+    pub multisig_enabled: bool, // Mints and revocations need officer approval
+    pub parent: Option<Pubkey>, // Accrediting issuer's ID; None for roots verified by the admins
+    pub depth: u8, // Levels below the root
+    pub path_len: u8, // Further levels of sub-issuers this issuer may accredit
+    pub name_account: Option<Pubkey>, // IssuerName claimed by this issuer, approved or pending
+    pub open_reports: u16, // Unresolved fraud reports against the issuer itself
+}
+
+impl IssuerAccount {
+    // discriminator + pubkey + authority + bump + status + created_at + verified_at + verified_until + suspended_at + revoked_at + status_updated_at + application_count + has_open_application + multisig_enabled + parent + depth + path_len + name_account + open_reports
+    pub const BASE_SPACE: usize = 8 + 32 + 32 + 1 + 1 + 8 + 9 + 9 + 9 + 9 + 8 + 4 + 1 + 1 + 33 + 1 + 1 + 33 + 2;
+
+    pub fn space(profile: &IssuerProfile, pending_profile: Option<&IssuerProfile>) -> usize {
+        Self::BASE_SPACE + profile.size() + 1 + pending_profile.map_or(0, IssuerProfile::size)
+    }
+
+    // Space needed once `new_profile` has been applied or staged by update_issuer_profile
+    pub fn space_after_update(&self, new_profile: &IssuerProfile) -> usize {
+        match self.status {
+            IssuerStatus::Verified => Self::space(&self.profile, Some(new_profile)),
+            _ => Self::space(new_profile, None),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct IssuerProfile {
+    pub display_name: String,
+    pub website: String,
+    pub logo_uri: String,
+    pub country_code: String, // ISO 3166-1 alpha-2, e.g. "NG"
+    pub registration_number: String, // Accreditation or company registration number
+    pub contact_uri: String,
+}
+
+impl IssuerProfile {
+    pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+    pub const MAX_WEBSITE_LEN: usize = 100;
+    pub const MAX_LOGO_URI_LEN: usize = 200;
+    pub const MAX_REGISTRATION_NUMBER_LEN: usize = 32;
+    pub const MAX_CONTACT_URI_LEN: usize = 100;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.display_name.len() <= Self::MAX_DISPLAY_NAME_LEN
+                && self.website.len() <= Self::MAX_WEBSITE_LEN
+                && self.logo_uri.len() <= Self::MAX_LOGO_URI_LEN
+                && self.registration_number.len() <= Self::MAX_REGISTRATION_NUMBER_LEN
+                && self.contact_uri.len() <= Self::MAX_CONTACT_URI_LEN,
+            CredVaultError::FieldTooLong
+        );
+        require!(
+            self.country_code.is_empty()
+                || (self.country_code.len() == 2 && self.country_code.bytes().all(|b| b.is_ascii_uppercase())),
+            CredVaultError::InvalidCountryCode
+        );
+        Ok(())
+    }
+
+    // Serialized size: each field is a length-prefixed string
+    pub fn size(&self) -> usize {
+        4 * 6
+            + self.display_name.len()
+            + self.website.len()
+            + self.logo_uri.len()
+            + self.country_code.len()
+            + self.registration_number.len()
+            + self.contact_uri.len()
+    }
+
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(keccak::hashv(&[&self.try_to_vec()?]).to_bytes())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IssuerStatus {
+    Pending,
+    Verified,
+    Suspended,
+    Revoked, // Terminal
+}
+
+impl IssuerStatus {
+    pub fn can_transition_to(&self, next: IssuerStatus) -> bool {
+        use IssuerStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Verified)
+                | (Pending, Revoked)
+                | (Verified, Pending)
+                | (Verified, Suspended)
+                | (Verified, Revoked)
+                | (Suspended, Verified)
+                | (Suspended, Revoked)
+        )
+    }
+}
+
+#[account]
+pub struct IssuerMultisig {
+    pub issuer_account: Pubkey,
+    pub officers: Vec<Pubkey>,
+    pub threshold: u8, // Officer approvals required to execute an operation
+    pub operation_count: u64,
+    pub bump: u8,
+}
+
+impl IssuerMultisig {
+    // discriminator + issuer_account + officers + threshold + operation_count + bump
+    pub const SPACE: usize = 8 + 32 + 4 + 32 * MAX_OFFICERS + 1 + 8 + 1;
+}
+
+#[account]
+pub struct IssuerOperation {
+    pub multisig: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub kind: IssuerOperationKind,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+    pub created_at: i64,
+    pub executed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl IssuerOperation {
+    pub const MAX_SKILL_LEN: usize = 50;
+    pub const MAX_URI_LEN: usize = 100;
+    // Largest kind: tag + student + skill_name + issue_date + uri + expires_at + co_issuers (MintCredential)
+    pub const KIND_SPACE: usize =
+        1 + 32 + 4 + Self::MAX_SKILL_LEN + 8 + 4 + Self::MAX_URI_LEN + 9 + 4 + 32 * MAX_CO_ISSUERS;
+    // discriminator + multisig + id + proposer + kind + approvals + executed + created_at + executed_at + bump
+    pub const SPACE: usize = 8 + 32 + 8 + 32 + Self::KIND_SPACE + 4 + 32 * MAX_OFFICERS + 1 + 8 + 9 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum IssuerOperationKind {
+    MintCredential {
+        student: Pubkey,
+        skill_name: String,
+        issue_date: i64,
+        credential_uri: String,
+        expires_at: Option<i64>,
+        co_issuers: Vec<Pubkey>,
+    },
+    RevokeCredential { credential: Pubkey, reason_code: u16, reason_uri: String },
+    CountersignCredential { credential: Pubkey },
+    RenewCredential { credential: Pubkey, new_expires_at: Option<i64> },
+    SetOfficers { officers: Vec<Pubkey>, threshold: u8 },
+}
+
+// A registered issuer name, keyed by the hash of its normalized form so each name maps to one issuer
+#[account]
+pub struct IssuerName {
+    pub name: String, // Normalized name or domain
+    pub issuer_pubkey: Pubkey,
+    pub approved: bool,
+    pub claimed_at: i64,
+    pub approved_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl IssuerName {
+    pub const MAX_NAME_LEN: usize = 64;
+    // discriminator + name + issuer_pubkey + approved + claimed_at + approved_at + bump
+    pub const SPACE: usize = 8 + 4 + Self::MAX_NAME_LEN + 32 + 1 + 8 + 9 + 1;
+
+    // Canonical form of a name or domain: lowercase, with runs of whitespace replaced by '-'
+    pub fn normalize(name: &str) -> String {
+        name.split_whitespace()
+            .collect::<Vec<_>>()
+            .join("-")
+            .to_ascii_lowercase()
+    }
+
+    // Whether `name` is already in canonical form and uses only a-z, 0-9, '.' and '-'
+    pub fn is_normalized(name: &str) -> bool {
+        !name.is_empty()
+            && name.len() <= Self::MAX_NAME_LEN
+            && name == Self::normalize(name)
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'.' || b == b'-')
+    }
+
+    pub fn seed(name: &str) -> [u8; 32] {
+        keccak::hash(name.as_bytes()).to_bytes()
+    }
+}
+
+#[account]
+pub struct TrustRegistry {
+    pub authority: Pubkey, // Creator; manages the maintainers
+    pub name: String,
+    pub maintainers: Vec<Pubkey>, // Keys that may add and remove issuers
+    pub entry_count: u32,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl TrustRegistry {
+    pub const MAX_NAME_LEN: usize = 32;
+    // discriminator + authority + name + maintainers + entry_count + created_at + bump
+    pub const SPACE: usize = 8 + 32 + 4 + Self::MAX_NAME_LEN + 4 + 32 * MAX_MAINTAINERS + 4 + 8 + 1;
+}
+
+// An issuer endorsed by a trust registry; removing the endorsement closes it
+#[account]
+pub struct RegistryEntry {
+    pub registry: Pubkey,
+    pub issuer_pubkey: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl RegistryEntry {
+    // discriminator + registry + issuer_pubkey + added_by + added_at + bump
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 8 + 1;
+}
+
+// Lamports staked by an issuer, held in this program-owned vault on top of its rent
+#[account]
+pub struct IssuerStake {
+    pub issuer_account: Pubkey,
+    pub amount: u64, // Bonded lamports
+    pub unbonding_amount: u64, // Requested for withdrawal; still slashable
+    pub unbonding_available_at: Option<i64>,
+    pub pending_cases: u32, // Open fraud cases; block unbonding and withdrawal
+    pub total_slashed: u64,
+    pub bump: u8,
+}
+
+impl IssuerStake {
+    // discriminator + issuer_account + amount + unbonding_amount + unbonding_available_at + pending_cases + total_slashed + bump
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 9 + 4 + 8 + 1;
+
+    pub fn slashable(&self) -> u64 {
+        self.amount.saturating_add(self.unbonding_amount)
+    }
+}
+
+// A fraud report against a credential or an issuer, one per reporter and target
+#[account]
+pub struct FraudReport {
+    pub issuer_account: Pubkey, // Reported issuer, or the reported credential's issuer
+    pub credential_account: Option<Pubkey>, // None for reports against the issuer itself
+    pub reporter: Pubkey,
+    pub evidence_uri: String,
+    pub evidence_hash: [u8; 32], // Content hash of the evidence
+    pub bond: u64, // Anti-spam bond in lamports, held until resolution
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl FraudReport {
+    pub const MAX_URI_LEN: usize = 200;
+    // discriminator + issuer_account + credential_account + reporter + evidence_uri + evidence_hash + bond + created_at + bump
+    pub const SPACE: usize = 8 + 32 + 33 + 32 + 4 + Self::MAX_URI_LEN + 32 + 8 + 8 + 1;
+
+    pub fn target(&self) -> Pubkey {
+        self.credential_account.unwrap_or(self.issuer_account)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReportStatus {
+    Dismissed,
+    CredentialRevoked,
+    IssuerSuspended,
+}
+
+#[account]
+pub struct IssuerDelegate {
+    pub issuer_account: Pubkey,
+    pub delegate: Pubkey, // Staff signing key
+    pub permissions: u8, // Bitmask of MINT, REVOKE and UPDATE_METADATA
+    pub allowed_skills: Vec<String>, // Empty means any skill
+    pub mint_quota: Option<u32>, // Max credentials per quota period; None means unlimited
+    pub quota_period: i64, // Length of a quota period in seconds
+    pub period_start: i64,
+    pub minted_in_period: u32,
+    pub expires_at: Option<i64>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl IssuerDelegate {
+    pub const MINT: u8 = 1 << 0;
+    pub const REVOKE: u8 = 1 << 1;
+    pub const UPDATE_METADATA: u8 = 1 << 2;
+    pub const ALL_PERMISSIONS: u8 = Self::MINT | Self::REVOKE | Self::UPDATE_METADATA;
+
+    pub const MAX_SKILLS: usize = 5;
+    pub const MAX_SKILL_LEN: usize = 50;
+    // discriminator + issuer_account + delegate + permissions + allowed_skills + mint_quota + quota_period + period_start + minted_in_period + expires_at + created_at + bump
+    pub const SPACE: usize =
+        8 + 32 + 32 + 1 + 4 + Self::MAX_SKILLS * (4 + Self::MAX_SKILL_LEN) + 5 + 8 + 8 + 4 + 9 + 8 + 1;
+}
+
+#[account]
+pub struct VerificationApplication {
+    pub issuer_account: Pubkey,
+    pub id: u32,
+    pub organization_name: String,
+    pub document_uri: String,     // Registration certificate or supporting documents
+    pub document_hash: [u8; 32],  // Content hash of the supporting documents
+    pub fee_paid: Option<u64>,    // Lamports paid into the treasury
+    pub stake: u64,               // Issuer's bonded stake when submitted
+    pub status: ApplicationStatus,
+    pub reason_code: Option<u16>, // Set by the admin decision
+    pub decided_by: Option<Pubkey>,
+    pub proposal_id: Option<u64>, // Deciding proposal
+    pub submitted_at: i64,
+    pub decided_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl VerificationApplication {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_URI_LEN: usize = 200;
+    // discriminator + issuer_account + id + organization_name + document_uri + document_hash + fee_paid + stake + status + reason_code + decided_by + proposal_id + submitted_at + decided_at + bump
+    pub const SPACE: usize = 8 + 32 + 4 + 4 + Self::MAX_NAME_LEN + 4 + Self::MAX_URI_LEN + 32 + 9 + 8 + 1 + 3 + 33 + 9 + 8 + 9 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ApplicationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[account]
+pub struct CredentialAccount {
+    pub issuer_pubkey: Pubkey,
+    pub student_pubkey: Pubkey,
+    pub skill_name: String,
+    pub issue_date: i64,
+    pub credential_uri: String,
+    pub is_soulbound: bool, // Non-transferable flag
+    pub is_revoked: bool,
+    pub created_at: i64,
+    pub revoked_at: Option<i64>,
+    pub expires_at: Option<i64>, // None for credentials that never expire
+    pub issued_by: Option<Pubkey>, // Delegate that issued the credential; None if the issuer's authority did
+    pub co_issuers: Vec<CoIssuer>, // Additional issuers that must countersign; empty for single-issuer credentials
+    pub issuer_verified_until: Option<i64>, // Issuer's verification expiry when the credential was minted
+    pub open_reports: u16, // Unresolved fraud reports against the credential
+    pub revocation_history: Vec<RevocationEvent>, // Revocations and appeals, oldest first
+    pub token_kind: CredentialTokenKind, // Token program the credential mint lives under
+    pub bump: u8,
+}
+
+impl CredentialAccount {
+    // discriminator + issuer + student + skill_name + issue_date + uri + soulbound + revoked + created_at + revoked_at + expires_at + issued_by + co_issuers + issuer_verified_until + open_reports + revocation_history (empty) + token_kind + bump
+    pub const SPACE: usize =
+        8 + 32 + 32 + 4 + 50 + 8 + 4 + 100 + 1 + 1 + 8 + 9 + 9 + 33 + 4 + CoIssuer::SPACE * MAX_CO_ISSUERS + 9 + 2 + 4 + 1 + 1;
+
+    // Allocated size, which grows with each revocation history entry
+    pub fn space(&self) -> usize {
+        Self::SPACE + self.revocation_history.iter().map(RevocationEvent::size).sum::<usize>()
+    }
+
+    // The entry recording the current revocation
+    pub fn revocation(&self) -> Option<&RevocationEvent> {
+        if !self.is_revoked {
+            return None;
+        }
+        self.revocation_history
+            .iter()
+            .rev()
+            .find(|event| event.action == RevocationAction::Revoked)
+    }
+
+    // A revocation can be appealed once, before anything else has happened to it
+    pub fn can_appeal(&self) -> bool {
+        self.is_revoked
+            && self
+                .revocation_history
+                .last()
+                .map_or(false, |event| event.action == RevocationAction::Revoked)
+    }
+
+    pub fn appeal_pending(&self) -> bool {
+        self.revocation_history
+            .last()
+            .map_or(false, |event| event.action == RevocationAction::AppealFiled)
+    }
+
+    // Whether `issuer_pubkey` is the credential's issuer or one of its co-issuers
+    pub fn is_issued_by(&self, issuer_pubkey: &Pubkey) -> bool {
+        self.issuer_pubkey == *issuer_pubkey
+            || self
+                .co_issuers
+                .iter()
+                .any(|co_issuer| co_issuer.issuer_pubkey == *issuer_pubkey)
+    }
+}
+
+// An entry in a credential's revocation history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RevocationEvent {
+    pub action: RevocationAction,
+    pub reason_code: Option<u16>, // Reason for a revocation or appeal decision; None for filings
+    pub uri: Option<String>, // Revocation reason or appeal statement
+    pub actor: Pubkey, // Revoker, student or deciding admin
+    pub at: i64,
+}
+
+impl RevocationEvent {
+    pub const MAX_URI_LEN: usize = 200;
+
+    // action + reason_code + uri + actor + at
+    pub fn size(&self) -> usize {
+        1 + 3 + 1 + self.uri.as_ref().map_or(0, |uri| 4 + uri.len()) + 32 + 8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RevocationAction {
+    Revoked,
+    AppealFiled,
+    AppealUpheld,
+    AppealOverturned, // The credential was reinstated
+}
+
+// A student's pending appeal against a revocation; closed once decided
+#[account]
+pub struct Appeal {
+    pub credential_account: Pubkey,
+    pub student: Pubkey,
+    pub statement_uri: String,
+    pub filed_at: i64,
+    pub bump: u8,
+}
+
+impl Appeal {
+    // discriminator + credential_account + student + statement_uri + filed_at + bump
+    pub const SPACE: usize = 8 + 32 + 32 + 4 + RevocationEvent::MAX_URI_LEN + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CoIssuer {
+    pub issuer_pubkey: Pubkey,
+    pub signed_at: Option<i64>, // None until the co-issuer countersigns
+}
+
+impl CoIssuer {
+    // issuer_pubkey + signed_at
+    pub const SPACE: usize = 32 + 9;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CredentialTokenKind {
+    SplToken,  // Legacy SPL Token + Metaplex metadata, frozen token account
+    Token2022, // Token-2022 with NonTransferable and MetadataPointer extensions
+}
+
+impl CredentialTokenKind {
+    pub fn from_program_id(program_id: &Pubkey) -> Result<Self> {
+        if *program_id == anchor_spl::token::ID {
+            Ok(Self::SplToken)
+        } else if *program_id == anchor_spl::token_2022::ID {
+            Ok(Self::Token2022)
+        } else {
+            err!(CredVaultError::UnsupportedTokenKind)
+        }
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Self::SplToken => anchor_spl::token::ID,
+            Self::Token2022 => anchor_spl::token_2022::ID,
+        }
+    }
+}
+
+#[account]
+pub struct CredentialTree {
+    pub issuer_pubkey: Pubkey,
+    pub merkle_tree: Pubkey, // Concurrent Merkle tree owned by the compression program
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub num_minted: u32, // Next leaf index
+    pub bump: u8,
+}
+
+// Leaf data of a compressed credential; only its hash is stored in the tree
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedCredential {
+    pub issuer_pubkey: Pubkey,
+    pub student_pubkey: Pubkey,
+    pub skill_name: String,
+    pub issue_date: i64,
+    pub credential_uri: String,
+    pub created_at: i64,
+    pub is_revoked: bool,
+    pub revoked_at: Option<i64>,
+    pub leaf_index: u32,
+}
+
+impl CompressedCredential {
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(keccak::hashv(&[&self.try_to_vec()?]).to_bytes())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CredentialStatus {
+    Valid,
+    Revoked,
+    Expired,
+    IssuerUnverified,
+    IssuerSuspended, // Created on or after the issuer's suspension took effect
+    IssuerRevoked,
+    TokenMismatch, // The supplied mint is not the credential's single token
+    AwaitingCountersignature, // A listed co-issuer has not countersigned yet
+    AccreditationInvalid, // An accreditor up the issuer's chain is not in good standing
+    IssuerNotInRegistry, // The issuer is not listed in the trust registry evaluated against
+    IssuerVerificationLapsed, // Created after the issuer's verification lapsed
+}
+
+// Returned from verify_credential via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VerificationResult {
+    pub status: CredentialStatus,
+    pub issuer_pubkey: Pubkey,
+    pub issuer_verified: bool, // Verified and not lapsed
+    pub issuer_status: IssuerStatus,
+    pub issuer_verified_until: Option<i64>,
+    pub skill_name: String,
+    pub issue_date: i64,
+    pub revoked_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub co_issuers: Vec<CoIssuerStanding>, // In the order the credential lists them
+    pub issuer_chain: Vec<IssuerChainLink>, // The issuer, then its accreditors up to the root
+    pub registry: Option<Pubkey>, // Trust registry evaluated against, if any
+    pub issuer_name: Option<String>, // The issuer's approved registered name, if supplied
+    pub issuer_stake: Option<u64>, // The issuer's bonded stake in lamports, if supplied
+    pub open_reports: u16, // Unresolved fraud reports against the credential
+    pub issuer_open_reports: u16, // Unresolved fraud reports against the issuer itself
+    pub revocation: Option<RevocationEvent>, // Reason for the current revocation, if recorded
+    pub appeal_pending: bool, // The student has appealed the current revocation
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct IssuerChainLink {
+    pub issuer_pubkey: Pubkey,
+    pub status: IssuerStatus,
+    pub depth: u8,
+}
+
+impl From<&IssuerAccount> for IssuerChainLink {
+    fn from(issuer: &IssuerAccount) -> Self {
+        Self {
+            issuer_pubkey: issuer.issuer_pubkey,
+            status: issuer.status,
+            depth: issuer.depth,
+        }
+    }
+}
+
+// A co-issuer's standing, reported by verify_credential
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CoIssuerStanding {
+    pub issuer_pubkey: Pubkey,
+    pub signed_at: Option<i64>,
+    pub issuer_status: IssuerStatus,
+    pub status: CredentialStatus, // Valid, or how this co-issuer affects the credential
+}
+
+// Returned from batch_mint_credentials via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchMintResult {
+    pub minted: u32,
+    pub skipped: Vec<Pubkey>, // Students that already held the credential
+}
+
+#[account]
+pub struct ProofOfWorkAccount {
+    pub student_pubkey: Pubkey,
+    pub project_title: String,
+    pub project_description: String,
+    pub github_link: String,
+    pub demo_link: String,
+    pub timestamp: i64,
+    pub is_transferable: bool, // Unlike SBTs, these can be transferred
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+// Context structs
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct InitializeIssuer<'info> {
+    #[account(
+        init,
+        seeds = [b"issuer", authority.key().as_ref()],
+        bump,
+        payer = authority,
+        space = IssuerAccount::space(&IssuerProfile::default(), None)
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RotateIssuerAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    pub authority: Signer<'info>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureIssuerMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        init,
+        seeds = [b"multisig", issuer_account.key().as_ref()],
+        bump,
+        payer = authority,
+        space = IssuerMultisig::SPACE
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeIssuerOperation<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.issuer_account.as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.officers.contains(&officer.key()) @ CredVaultError::NotAnOfficer
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+
+    #[account(
+        init,
+        seeds = [b"issuer-op", multisig.key().as_ref(), multisig.operation_count.to_le_bytes().as_ref()],
+        bump,
+        payer = officer,
+        space = IssuerOperation::SPACE
+    )]
+    pub operation: Account<'info, IssuerOperation>,
+
+    #[account(mut)]
+    pub officer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveIssuerOperation<'info> {
+    #[account(
+        seeds = [b"multisig", multisig.issuer_account.as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.officers.contains(&officer.key()) @ CredVaultError::NotAnOfficer
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer-op", multisig.key().as_ref(), operation.id.to_le_bytes().as_ref()],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, IssuerOperation>,
+
+    pub officer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOfficerChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.issuer_account.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, IssuerMultisig>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer-op", multisig.key().as_ref(), operation.id.to_le_bytes().as_ref()],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, IssuerOperation>,
+
+    pub officer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct ClaimIssuerName<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    // Fails to initialize if another issuer already holds the name
+    #[account(
+        init,
+        seeds = [b"issuer-name", IssuerName::seed(&name).as_ref()],
+        bump,
+        payer = authority,
+        space = IssuerName::SPACE
+    )]
+    pub issuer_name: Account<'info, IssuerName>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddIssuerDelegate<'info> {
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        init,
+        seeds = [b"delegate", issuer_account.key().as_ref(), delegate_key.key().as_ref()],
+        bump,
+        payer = authority,
+        space = IssuerDelegate::SPACE
+    )]
+    pub delegate: Account<'info, IssuerDelegate>,
+
+    /// CHECK: Any key can be made a delegate; it only needs to sign when acting
+    pub delegate_key: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveIssuerDelegate<'info> {
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"delegate", issuer_account.key().as_ref(), delegate.delegate.as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Account<'info, IssuerDelegate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(profile: IssuerProfile)]
+pub struct UpdateIssuerProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer,
+        realloc = issuer_account.space_after_update(&profile),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        seeds = [b"config"],
+        bump,
+        payer = authority,
+        space = 8 + 4 + 32 * MAX_ADMINS + 1 + 8 + 1 + 8 // discriminator + admins + threshold + proposal_count + bump + created_at
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::CredVault>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ CredVaultError::UnauthorizedAdmin)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeIssuerStatus<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeProfileApproval<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthorityRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeApplicationApproval<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"application", application.issuer_account.as_ref(), application.id.to_le_bytes().as_ref()],
+        bump = application.bump
+    )]
+    pub application: Account<'info, VerificationApplication>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeApplicationRejection<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"application", application.issuer_account.as_ref(), application.id.to_le_bytes().as_ref()],
+        bump = application.bump
+    )]
+    pub application: Account<'info, VerificationApplication>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeNameApproval<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer-name", IssuerName::seed(&issuer_name.name).as_ref()],
+        bump = issuer_name.bump
+    )]
+    pub issuer_name: Account<'info, IssuerName>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeNameRejection<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer-name", IssuerName::seed(&issuer_name.name).as_ref()],
+        bump = issuer_name.bump
+    )]
+    pub issuer_name: Account<'info, IssuerName>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeStakeSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"stake", issuer_stake.issuer_account.as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Account<'info, IssuerStake>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdminChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&approver.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&executor.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Only required for issuer status, application, name rejection, profile, authority recovery, renewal, accreditation rights and fraud report proposals
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Option<Account<'info, IssuerAccount>>,
+
+    // Only required for application proposals
+    #[account(
+        mut,
+        seeds = [b"application", application.issuer_account.as_ref(), application.id.to_le_bytes().as_ref()],
+        bump = application.bump
+    )]
+    pub application: Option<Account<'info, VerificationApplication>>,
+
+    // Only required for name proposals; closed when a claim is rejected
+    #[account(
+        mut,
+        seeds = [b"issuer-name", IssuerName::seed(&issuer_name.name).as_ref()],
+        bump = issuer_name.bump
+    )]
+    pub issuer_name: Option<Account<'info, IssuerName>>,
+
+    // Only required for stake slash proposals and upheld fraud reports, where it opens a stake case
+    #[account(
+        mut,
+        seeds = [b"stake", issuer_stake.issuer_account.as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Option<Account<'info, IssuerStake>>,
+
+    /// CHECK: Receives slashed or withdrawn lamports, or a closed report, appeal or name claim; must match the proposal's recipient, the reporter, the student or the issuer's authority
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    // Only required for treasury withdrawal proposals and dismissed reports with a bond
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: Option<SystemAccount<'info>>,
+
+    // Only required for fraud report proposals; closed on execution
+    #[account(
+        mut,
+        seeds = [b"fraud-report", fraud_report.target().as_ref(), fraud_report.reporter.as_ref()],
+        bump = fraud_report.bump
+    )]
+    pub fraud_report: Option<Account<'info, FraudReport>>,
+
+    // Only required for appeal proposals and fraud report proposals against a credential
+    #[account(mut)]
+    pub credential_account: Option<Account<'info, CredentialAccount>>,
+
+    // Only required for appeal proposals; closed on execution
+    #[account(
+        mut,
+        seeds = [b"appeal", appeal.credential_account.as_ref()],
+        bump = appeal.bump
+    )]
+    pub appeal: Option<Account<'info, Appeal>>,
+
+    // Pays for the revocation history entry when a report revokes a credential or an appeal is decided
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    pub system_program: Option<Program<'info, System>>, // Only required for treasury withdrawal, credential revocation and appeal proposals
+}
+
+#[derive(Accounts)]
+pub struct ProposeVerificationRenewal<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAccreditationRights<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeTreasuryWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeFraudReportResolution<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"fraud-report", fraud_report.target().as_ref(), fraud_report.reporter.as_ref()],
+        bump = fraud_report.bump
+    )]
+    pub fraud_report: Account<'info, FraudReport>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyForVerification<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        init,
+        seeds = [b"application", issuer_account.key().as_ref(), issuer_account.application_count.to_le_bytes().as_ref()],
+        bump,
+        payer = authority,
+        space = VerificationApplication::SPACE
+    )]
+    pub application: Account<'info, VerificationApplication>,
+
+    // Program treasury collecting application fees
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [b"stake", issuer_account.key().as_ref()],
+        bump,
+        payer = authority,
+        space = IssuerStake::SPACE
+    )]
+    pub issuer_stake: Account<'info, IssuerStake>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SuspendIssuer<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&admin.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AccreditIssuer<'info> {
+    #[account(
+        seeds = [b"issuer", parent_account.issuer_pubkey.as_ref()],
+        bump = parent_account.bump,
+        constraint = parent_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer,
+        constraint = parent_account.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub parent_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer", child_account.issuer_pubkey.as_ref()],
+        bump = child_account.bump
+    )]
+    pub child_account: Account<'info, IssuerAccount>,
+
+    pub authority: Signer<'info>, // The parent's authority
+}
+
+#[derive(Accounts)]
+pub struct SetSubIssuerStatus<'info> {
+    #[account(
+        seeds = [b"issuer", parent_account.issuer_pubkey.as_ref()],
+        bump = parent_account.bump,
+        constraint = parent_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer,
+        constraint = parent_account.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub parent_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer", child_account.issuer_pubkey.as_ref()],
+        bump = child_account.bump
+    )]
+    pub child_account: Account<'info, IssuerAccount>,
+
+    pub authority: Signer<'info>, // The parent's authority
+}
+
+#[derive(Accounts)]
+pub struct CascadeIssuerRevocation<'info> {
+    #[account(
+        seeds = [b"issuer", parent_account.issuer_pubkey.as_ref()],
+        bump = parent_account.bump,
+        constraint = parent_account.status == IssuerStatus::Revoked @ CredVaultError::InvalidIssuerTransition
+    )]
+    pub parent_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer", child_account.issuer_pubkey.as_ref()],
+        bump = child_account.bump
+    )]
+    pub child_account: Account<'info, IssuerAccount>,
+}
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", issuer_account.key().as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Account<'info, IssuerStake>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", issuer_account.key().as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Account<'info, IssuerStake>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == authority.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", issuer_account.key().as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Account<'info, IssuerStake>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveStakeCase<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&admin.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", issuer_stake.issuer_account.as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Account<'info, IssuerStake>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReportCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    // Optional: the reported credential; the issuer itself is reported when omitted
+    #[account(
+        mut,
+        seeds = [b"credential",
+                credential_account.student_pubkey.as_ref(),
+                credential_account.issuer_pubkey.as_ref(),
+                get_skill_seed(&credential_account.skill_name)],
+        bump = credential_account.bump,
+        constraint = credential_account.issuer_pubkey == issuer_account.issuer_pubkey @ CredVaultError::InvalidReportTarget
+    )]
+    pub credential_account: Option<Account<'info, CredentialAccount>>,
+
+    #[account(
+        init,
+        seeds = [b"fraud-report", report_target(&issuer_account, &credential_account).as_ref(), reporter.key().as_ref()],
+        bump,
+        payer = reporter,
+        space = FraudReport::SPACE
+    )]
+    pub fraud_report: Account<'info, FraudReport>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(skill_name: String)]
+pub struct MintCredential<'info> {
+    #[account(
+        init,
+        seeds = [b"credential", student.key().as_ref(), issuer.issuer_pubkey.as_ref(), skill_name.as_bytes()],
+        bump,
+        payer = authority,
+        space = CredentialAccount::SPACE
+    )]
+    pub credential_account: Account<'info, CredentialAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer.issuer_pubkey.as_ref()],
+        bump = issuer.bump,
+        constraint = issuer.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub issuer: Account<'info, IssuerAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>, // The issuer's current signing key, a delegate or an officer
+
+    // Required when `authority` is a delegate rather than the issuer's authority
+    #[account(
+        mut,
+        seeds = [b"delegate", issuer.key().as_ref(), authority.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+
+    // Required for multisig issuers, with `authority` being one of the officers
+    #[account(seeds = [b"multisig", issuer.key().as_ref()], bump = multisig.bump)]
+    pub multisig: Option<Account<'info, IssuerMultisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer-op", operation.multisig.as_ref(), operation.id.to_le_bytes().as_ref()],
+        bump = operation.bump
+    )]
+    pub operation: Option<Account<'info, IssuerOperation>>,
+    
+    #[account(mut)]
+    pub student: Signer<'info>,
+    
+    /// CHECK: We're creating this account (SPL Token credentials only)
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), credential_mint.key().as_ref()],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub token_metadata: Option<AccountInfo<'info>>,
+    
+    /// CHECK: Created and initialized in the instruction under `token_program`
+    #[account(
+        mut,
+        seeds = [b"mint", credential_account.key().as_ref()],
+        bump,
+    )]
+    pub credential_mint: AccountInfo<'info>,
+    
+    /// CHECK: The student's associated token account, created in the instruction
+    #[account(
+        mut,
+        address = anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &student.key(),
+            &credential_mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    pub credential_token_account: AccountInfo<'info>,
+    
+    /// CHECK: Program PDA that holds the mint, freeze and update authority over credentials
+    #[account(seeds = [b"authority"], bump)]
+    pub program_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Must be the Metaplex program, since the program PDA signs CPIs into it
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>, // SPL Token or Token-2022
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct BatchMintCredentials<'info> {
+    #[account(
+        seeds = [b"issuer", issuer.issuer_pubkey.as_ref()],
+        bump = issuer.bump,
+        constraint = issuer.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub issuer: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>, // The issuer's current signing key or a delegate; pays for every credential in the batch
+
+    // Required when `authority` is a delegate rather than the issuer's authority
+    #[account(
+        mut,
+        seeds = [b"delegate", issuer.key().as_ref(), authority.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+
+    /// CHECK: Program PDA that holds the mint, freeze and update authority over credentials
+    #[account(seeds = [b"authority"], bump)]
+    pub program_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the Metaplex program, since the program PDA signs CPIs into it
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>, // SPL Token or Token-2022
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
 }
 
-// Context structs
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct InitializeIssuer<'info> {
+pub struct CreateCredentialTree<'info> {
     #[account(
         init,
-        seeds = [b"issuer", authority.key().as_ref()],
+        seeds = [b"credential-tree", merkle_tree.key().as_ref()],
         bump,
         payer = authority,
-        space = 8 + 32 + 1 + 1 + 8 // discriminator + pubkey + bump + is_verified + created_at
+        space = 8 + 32 + 32 + 4 + 4 + 4 + 1 // discriminator + issuer + merkle_tree + max_depth + max_buffer_size + num_minted + bump
     )]
-    pub issuer_account: Account<'info, IssuerAccount>,
+    pub tree_config: Account<'info, CredentialTree>,
+
+    /// CHECK: Zeroed tree account, validated and initialized by the compression program
+    #[account(mut, owner = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"issuer", issuer.issuer_pubkey.as_ref()],
+        bump = issuer.bump,
+        constraint = issuer.authority == authority.key() @ CredVaultError::UnauthorizedIssuer,
+        constraint = issuer.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub issuer: Account<'info, IssuerAccount>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    /// CHECK: The SPL account compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: The SPL noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct MintCredential<'info> {
+pub struct MintCompressedCredential<'info> {
     #[account(
-        init,
-        seeds = [b"credential", student.key().as_ref(), issuer.issuer_pubkey.as_ref(), skill_name.as_bytes()],
-        bump,
-        payer = issuer_account,
-        space = 8 + 32 + 32 + 50 + 8 + 100 + 1 + 1 + 8 + 9 + 1 // discriminator + issuer + student + skill_name + issue_date + uri + soulbound + revoked + created_at + revoked_at + bump
+        mut,
+        seeds = [b"credential-tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        constraint = tree_config.issuer_pubkey == issuer.issuer_pubkey @ CredVaultError::UnauthorizedIssuer
     )]
-    pub credential_account: Account<'info, CredentialAccount>,
-    
+    pub tree_config: Account<'info, CredentialTree>,
+
+    /// CHECK: Validated by the compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
     #[account(
-        mut,
-        seeds = [b"issuer", issuer_pubkey.key().as_ref()],
+        seeds = [b"issuer", issuer.issuer_pubkey.as_ref()],
         bump = issuer.bump,
-        constraint = issuer.issuer_pubkey == issuer_pubkey.key() @ CredVaultError::InvalidIssuerAccount,
-        constraint = issuer.is_verified @ CredVaultError::IssuerNotVerified
+        constraint = issuer.authority == authority.key() @ CredVaultError::UnauthorizedIssuer,
+        constraint = issuer.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
     )]
     pub issuer: Account<'info, IssuerAccount>,
-    
-    #[account(mut)]
-    pub issuer_pubkey: Signer<'info>, // The actual issuer account signing the transaction that matches the issuer account
-    
-    #[account(mut)]
-    pub student: Signer<'info>,
-    
-    /// CHECK: We're creating this account
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: The SPL account compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: The SPL noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyCompressedCredential<'info> {
     #[account(
-        mut,
-        seeds = [b"metadata", credential_mint.key().as_ref()],
-        bump,
-        seeds::program = mpl_token_metadata::ID,
+        seeds = [b"credential-tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump
     )]
-    pub token_metadata: AccountInfo<'info>,
-    
+    pub tree_config: Account<'info, CredentialTree>,
+
+    /// CHECK: Validated by the compression program
+    pub merkle_tree: UncheckedAccount<'info>,
+
     #[account(
-        init,
-        payer = issuer_pubkey,
-        seeds = [b"mint", credential_account.key().as_ref()],
-        bump,
-        mint::decimals = 0,
-        mint::freeze_authority = issuer_pubkey,
-        mint::mint_authority = issuer_pubkey,
+        seeds = [b"issuer", tree_config.issuer_pubkey.as_ref()],
+        bump = issuer.bump
     )]
-    pub credential_mint: Account<'info, Mint>,
-    
+    pub issuer: Account<'info, IssuerAccount>,
+
+    /// CHECK: The SPL account compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    // Optional: the issuer's stake vault, whose bonded amount is reported
     #[account(
-        init,
-        payer = issuer_pubkey,
-        seeds = [b"token", credential_account.key().as_ref()],
-        bump,
-        token::mint = credential_mint,
-        token::authority = issuer_pubkey,
+        seeds = [b"stake", issuer.key().as_ref()],
+        bump = issuer_stake.bump
     )]
-    pub credential_token_account: Account<'info, TokenAccount>,
-    
+    pub issuer_stake: Option<Account<'info, IssuerStake>>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeCompressedCredential<'info> {
     #[account(
-        mut,
-        seeds = [b"master-edition", credential_mint.key().as_ref()],
-        bump,
-        seeds::program = mpl_token_metadata::ID,
+        seeds = [b"credential-tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump
     )]
-    /// CHECK: We're creating this PDA
-    pub master_edition: AccountInfo<'info>,
-    
-    pub metadata_program: UncheckedAccount<'info>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub tree_config: Account<'info, CredentialTree>,
+
+    /// CHECK: Validated by the compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"issuer", tree_config.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.authority == issuer.key() @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    pub issuer: Signer<'info>, // The issuer trying to revoke
+
+    /// CHECK: The SPL account compression program
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: The SPL noop program
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -570,6 +5020,122 @@ pub struct VerifyCredential<'info> {
         bump = credential_account.bump
     )]
     pub credential_account: Account<'info, CredentialAccount>,
+
+    #[account(
+        seeds = [b"issuer", credential_account.issuer_pubkey.as_ref()],
+        bump = issuer.bump
+    )]
+    pub issuer: Account<'info, IssuerAccount>,
+
+    // Optional: either a legacy SPL Token or a Token-2022 credential mint
+    #[account(
+        seeds = [b"mint", credential_account.key().as_ref()],
+        bump
+    )]
+    pub credential_mint: Option<InterfaceAccount<'info, token_interface::Mint>>,
+
+    // Optional: a trust registry to evaluate the issuer against
+    pub registry: Option<Account<'info, TrustRegistry>>,
+
+    /// CHECK: The issuer's entry PDA in `registry`; checked in the instruction, may not exist
+    pub registry_entry: Option<UncheckedAccount<'info>>,
+
+    // Optional: the issuer's registered name, reported once approved
+    #[account(
+        seeds = [b"issuer-name", IssuerName::seed(&issuer_name.name).as_ref()],
+        bump = issuer_name.bump,
+        constraint = issuer_name.issuer_pubkey == credential_account.issuer_pubkey @ CredVaultError::InvalidIssuerName
+    )]
+    pub issuer_name: Option<Account<'info, IssuerName>>,
+
+    // Optional: the issuer's stake vault, whose bonded amount is reported
+    #[account(
+        seeds = [b"stake", issuer.key().as_ref()],
+        bump = issuer_stake.bump
+    )]
+    pub issuer_stake: Option<Account<'info, IssuerStake>>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTrustRegistry<'info> {
+    #[account(
+        init,
+        seeds = [b"trust-registry", authority.key().as_ref(), name.as_bytes()],
+        bump,
+        payer = authority,
+        space = TrustRegistry::SPACE
+    )]
+    pub registry: Account<'info, TrustRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRegistryMaintainers<'info> {
+    #[account(
+        mut,
+        seeds = [b"trust-registry", registry.authority.as_ref(), registry.name.as_bytes()],
+        bump = registry.bump,
+        constraint = registry.authority == authority.key() @ CredVaultError::UnauthorizedRegistryMaintainer
+    )]
+    pub registry: Account<'info, TrustRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddRegistryIssuer<'info> {
+    #[account(
+        mut,
+        seeds = [b"trust-registry", registry.authority.as_ref(), registry.name.as_bytes()],
+        bump = registry.bump,
+        constraint = registry.maintainers.contains(&maintainer.key()) @ CredVaultError::UnauthorizedRegistryMaintainer
+    )]
+    pub registry: Account<'info, TrustRegistry>,
+
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(
+        init,
+        seeds = [b"registry-entry", registry.key().as_ref(), issuer_account.issuer_pubkey.as_ref()],
+        bump,
+        payer = maintainer,
+        space = RegistryEntry::SPACE
+    )]
+    pub entry: Account<'info, RegistryEntry>,
+
+    #[account(mut)]
+    pub maintainer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveRegistryIssuer<'info> {
+    #[account(
+        mut,
+        seeds = [b"trust-registry", registry.authority.as_ref(), registry.name.as_bytes()],
+        bump = registry.bump,
+        constraint = registry.maintainers.contains(&maintainer.key()) @ CredVaultError::UnauthorizedRegistryMaintainer
+    )]
+    pub registry: Account<'info, TrustRegistry>,
+
+    #[account(
+        mut,
+        close = maintainer,
+        seeds = [b"registry-entry", registry.key().as_ref(), entry.issuer_pubkey.as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, RegistryEntry>,
+
+    #[account(mut)]
+    pub maintainer: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -596,15 +5162,25 @@ pub struct UpdateMetadata<'info> {
     pub credential_account: Account<'info, CredentialAccount>,
     
     #[account(
-        mut,
-        constraint = issuer.key() == credential_account.issuer_pubkey @ CredVaultError::UnauthorizedIssuer
+        seeds = [b"issuer", credential_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>, // The issuer's authority or a delegate
+
+    // Required when `issuer` is a delegate rather than the issuer's authority
+    #[account(
+        seeds = [b"delegate", issuer_account.key().as_ref(), issuer.key().as_ref()],
+        bump = delegate.bump
     )]
-    pub issuer: Signer<'info>,
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
     
     /// CHECK: We're updating this account
     #[account(
         mut,
-        seeds = [b"metadata", mint.key().as_ref()],
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
         bump,
         seeds::program = mpl_token_metadata::ID,
     )]
@@ -617,7 +5193,13 @@ pub struct UpdateMetadata<'info> {
     )]
     pub mint: Account<'info, Mint>,
     
-    pub metadata_program: Program<'info, Metadata>,
+    /// CHECK: Program PDA that holds the update authority over credential metadata
+    #[account(seeds = [b"authority"], bump)]
+    pub program_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Must be the Metaplex program, since the program PDA signs CPIs into it
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -670,11 +5252,174 @@ pub struct RevokeCredential<'info> {
     )]
     pub credential_account: Account<'info, CredentialAccount>,
     
+    // The credential's issuer or one of its co-issuers; each may revoke independently
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = credential_account.is_issued_by(&issuer_account.issuer_pubkey) @ CredVaultError::UnauthorizedIssuer
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>, // The issuer's authority or a delegate
+
+    // Required when `issuer` is a delegate rather than the issuer's authority
+    #[account(
+        seeds = [b"delegate", issuer_account.key().as_ref(), issuer.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+
+    // Required for multisig issuers, with `issuer` being one of the officers
+    #[account(seeds = [b"multisig", issuer_account.key().as_ref()], bump = multisig.bump)]
+    pub multisig: Option<Account<'info, IssuerMultisig>>,
+
     #[account(
         mut,
-        constraint = issuer.key() == credential_account.issuer_pubkey @ CredVaultError::UnauthorizedIssuer
+        seeds = [b"issuer-op", operation.multisig.as_ref(), operation.id.to_le_bytes().as_ref()],
+        bump = operation.bump
     )]
-    pub issuer: Signer<'info>, // The issuer trying to revoke
+    pub operation: Option<Account<'info, IssuerOperation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FileAppeal<'info> {
+    #[account(
+        mut,
+        seeds = [b"credential",
+                credential_account.student_pubkey.as_ref(),
+                credential_account.issuer_pubkey.as_ref(),
+                get_skill_seed(&credential_account.skill_name)],
+        bump = credential_account.bump,
+        constraint = credential_account.student_pubkey == student.key() @ CredVaultError::NotCredentialHolder
+    )]
+    pub credential_account: Account<'info, CredentialAccount>,
+
+    #[account(
+        init,
+        seeds = [b"appeal", credential_account.key().as_ref()],
+        bump,
+        payer = student,
+        space = Appeal::SPACE
+    )]
+    pub appeal: Account<'info, Appeal>,
+
+    #[account(mut)]
+    pub student: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAppealResolution<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admins.contains(&proposer.key()) @ CredVaultError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [b"proposal", config.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::SPACE
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"appeal", appeal.credential_account.as_ref()],
+        bump = appeal.bump
+    )]
+    pub appeal: Account<'info, Appeal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CountersignCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"credential", 
+                credential_account.student_pubkey.as_ref(), 
+                credential_account.issuer_pubkey.as_ref(), 
+                get_skill_seed(&credential_account.skill_name)],
+        bump = credential_account.bump
+    )]
+    pub credential_account: Account<'info, CredentialAccount>,
+
+    // The co-issuer countersigning
+    #[account(
+        seeds = [b"issuer", issuer_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    pub authority: Signer<'info>, // The co-issuer's authority, a delegate or an officer
+
+    #[account(
+        mut,
+        seeds = [b"delegate", issuer_account.key().as_ref(), authority.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+
+    #[account(seeds = [b"multisig", issuer_account.key().as_ref()], bump = multisig.bump)]
+    pub multisig: Option<Account<'info, IssuerMultisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer-op", operation.multisig.as_ref(), operation.id.to_le_bytes().as_ref()],
+        bump = operation.bump
+    )]
+    pub operation: Option<Account<'info, IssuerOperation>>,
+}
+
+#[derive(Accounts)]
+pub struct RenewCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"credential", 
+                credential_account.student_pubkey.as_ref(), 
+                credential_account.issuer_pubkey.as_ref(), 
+                get_skill_seed(&credential_account.skill_name)],
+        bump = credential_account.bump
+    )]
+    pub credential_account: Account<'info, CredentialAccount>,
+
+    #[account(
+        seeds = [b"issuer", credential_account.issuer_pubkey.as_ref()],
+        bump = issuer_account.bump,
+        constraint = issuer_account.status == IssuerStatus::Verified @ CredVaultError::IssuerNotVerified
+    )]
+    pub issuer_account: Account<'info, IssuerAccount>,
+
+    pub issuer: Signer<'info>, // The issuer's authority or a delegate
+
+    // Required when `issuer` is a delegate rather than the issuer's authority
+    #[account(
+        mut,
+        seeds = [b"delegate", issuer_account.key().as_ref(), issuer.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, IssuerDelegate>>,
+
+    // Required for multisig issuers, with `issuer` being one of the officers
+    #[account(seeds = [b"multisig", issuer_account.key().as_ref()], bump = multisig.bump)]
+    pub multisig: Option<Account<'info, IssuerMultisig>>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer-op", operation.multisig.as_ref(), operation.id.to_le_bytes().as_ref()],
+        bump = operation.bump
+    )]
+    pub operation: Option<Account<'info, IssuerOperation>>,
 }
 
 // Helper function to create seed from skill name
@@ -706,10 +5451,130 @@ pub enum CredVaultError {
     UnauthorizedIssuer,
     #[msg("Credential has been revoked")]
     CredentialRevoked,
-    #[msg("Credential is not soulbound")]
-    NotSoulbound,
     #[msg("Only the owner can update this")]
     UnauthorizedUpdate,
     #[msg("Invalid issuer account provided")]
     InvalidIssuerAccount,
+    #[msg("Only a governance admin can perform this action")]
+    UnauthorizedAdmin,
+    #[msg("Too many admins")]
+    TooManyAdmins,
+    #[msg("Admin is already in the admin set")]
+    DuplicateAdmin,
+    #[msg("Admin not found in the admin set")]
+    AdminNotFound,
+    #[msg("Threshold must be between 1 and the number of admins")]
+    InvalidThreshold,
+    #[msg("Admin has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal does not have enough admin approvals")]
+    InsufficientApprovals,
+    #[msg("Account does not match the proposal target")]
+    ProposalTargetMismatch,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Token program must be SPL Token or Token-2022")]
+    UnsupportedTokenKind,
+    #[msg("Metaplex metadata account is required for SPL Token credentials")]
+    MissingMetadataAccounts,
+    #[msg("Batch remaining accounts are missing or do not match the expected PDAs")]
+    InvalidBatchAccounts,
+    #[msg("Every student receiving a credential must sign the transaction")]
+    StudentSignatureRequired,
+    #[msg("Expiry must be later than the issue date and the current expiry")]
+    InvalidExpiry,
+    #[msg("Issuer cannot move from its current status to the requested one")]
+    InvalidIssuerTransition,
+    #[msg("Suspension cannot take effect in the future")]
+    InvalidSuspensionTime,
+    #[msg("Field exceeds its maximum length")]
+    FieldTooLong,
+    #[msg("Issuer already has an open verification application")]
+    ApplicationAlreadyOpen,
+    #[msg("Application has already been decided")]
+    ApplicationNotPending,
+    #[msg("Country code must be a two-letter uppercase ISO 3166-1 code")]
+    InvalidCountryCode,
+    #[msg("Issuer profile cannot be changed while suspended or revoked")]
+    ProfileLocked,
+    #[msg("Issuer has no profile change awaiting approval")]
+    NoPendingProfile,
+    #[msg("New authority must differ from the current one")]
+    InvalidNewAuthority,
+    #[msg("Delegate permissions or quota settings are invalid")]
+    InvalidDelegateConfig,
+    #[msg("Delegate has expired")]
+    DelegateExpired,
+    #[msg("Delegate is not allowed to act on this skill")]
+    SkillNotDelegated,
+    #[msg("Delegate has reached its mint quota for this period")]
+    DelegateQuotaExceeded,
+    #[msg("Issuer requires an officer-approved operation for this action")]
+    MultisigRequired,
+    #[msg("Officer list is empty, too long or has duplicates")]
+    InvalidOfficers,
+    #[msg("Only an issuer officer can perform this action")]
+    NotAnOfficer,
+    #[msg("Operation does not match the requested action")]
+    OperationMismatch,
+    #[msg("Co-issuer list is too long, has duplicates or includes the issuer")]
+    InvalidCoIssuers,
+    #[msg("Issuer is not a co-issuer of this credential")]
+    NotACoIssuer,
+    #[msg("Co-issuer has already countersigned this credential")]
+    AlreadyCountersigned,
+    #[msg("Co-issuer accounts do not match the credential's co-issuers")]
+    InvalidCoIssuerAccounts,
+    #[msg("Issuer is not the parent of this sub-issuer")]
+    NotParentIssuer,
+    #[msg("Only pending issuers can be accredited")]
+    IssuerNotPending,
+    #[msg("Accreditation depth limit exceeded")]
+    AccreditationDepthExceeded,
+    #[msg("Issuer chain accounts are missing or out of order")]
+    InvalidIssuerChain,
+    #[msg("Maintainer list is empty, too long or has duplicates")]
+    InvalidMaintainers,
+    #[msg("Only a registry maintainer can perform this action")]
+    UnauthorizedRegistryMaintainer,
+    #[msg("Registry entry account does not match the registry and issuer")]
+    InvalidRegistryEntry,
+    #[msg("Issuer name is not normalized or does not belong to this issuer")]
+    InvalidIssuerName,
+    #[msg("Issuer has already claimed a name")]
+    NameAlreadyClaimed,
+    #[msg("Name claim is not pending")]
+    NameNotPending,
+    #[msg("Issuer's verification has lapsed and must be renewed")]
+    VerificationLapsed,
+    #[msg("Verification can only be renewed for up to one period from now")]
+    InvalidVerificationPeriod,
+    #[msg("Stake is below the required minimum or the amount exceeds the available stake")]
+    InsufficientStake,
+    #[msg("Stake is still unbonding")]
+    StakeUnbonding,
+    #[msg("Stake has a pending case")]
+    PendingStakeCase,
+    #[msg("Stake cannot be unbonded while the issuer is suspended or has open fraud reports")]
+    StakeUnderInvestigation,
+    #[msg("No stake case is pending")]
+    NoPendingStakeCase,
+    #[msg("No unbonded stake to withdraw")]
+    NothingToWithdraw,
+    #[msg("Reported account does not match the report")]
+    InvalidReportTarget,
+    #[msg("Resolution is not valid for this report")]
+    InvalidReportResolution,
+    #[msg("The issuer's stake account, or its uninitialised address, is required to uphold a report")]
+    IssuerStakeRequired,
+    #[msg("Only the credential's student can perform this action")]
+    NotCredentialHolder,
+    #[msg("Credential is not revoked or its revocation has already been appealed")]
+    AppealNotAllowed,
+    #[msg("Credential has no appeal awaiting a decision")]
+    AppealNotPending,
+    #[msg("The treasury cannot pay out this amount and stay rent-exempt")]
+    InsufficientTreasuryBalance,
 }
\ No newline at end of file
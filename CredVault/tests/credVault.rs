@@ -1,18 +1,19 @@
 use anchor_lang::prelude::Clock;
 use anchor_test::{
-    anchor_lang::{AccountSerialize, InstructionData, ToAccountInfos},
+    anchor_lang::{AccountDeserialize, AccountSerialize, AnchorDeserialize, InstructionData, ToAccountInfos, ToAccountMetas},
     solana_sdk::{
         account::Account, 
-        instruction::Instruction, 
+        instruction::{AccountMeta, Instruction, InstructionError},
         program_pack::Pack, 
         pubkey::Pubkey, 
         rent::Rent, 
         signature::{Keypair, Signer}, 
         system_instruction, 
         sysvar,
-        transaction::Transaction,
+        transaction::{Transaction, TransactionError},
     },
     ProgramTest, 
+    ProgramTestContext,
     BanksClient
 };
 use credVault::CredVault;
@@ -93,138 +94,91 @@ async fn test_mint_credential() {
         None,
     );
 
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
     let mut ctx = program_test.start_with_context().await;
-    
-    // Create issuer and student keypairs
     let issuer = Keypair::new();
     let student = Keypair::new();
-    
-    // Fund the issuer account
-    let rent = Rent::default();
-    let rent_lamports = rent.minimum_balance(0);
-    
-    let fund_issuer_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &issuer.pubkey(),
-        rent_lamports.max(1_000_000), // Ensure sufficient funds
-    );
-    
-    let fund_student_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &student.pubkey(),
-        rent_lamports.max(1_000_000),
-    );
-    
-    let fund_tx = Transaction::new_signed_with_payer(
-        &[fund_issuer_ix, fund_student_ix],
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer],
-        ctx.last_blockhash,
-    );
-    
-    ctx.banks_client.process_transaction(fund_tx).await.unwrap();
-    
-    // First, initialize an issuer (in a real scenario, this would be done by an admin)
-    // For this test, we'll manually create an issuer account
-    let issuer_account_key = Pubkey::create_program_address(
-        &[b"issuer", issuer.pubkey().to_bytes().as_ref(), &[2]], // bump = 2
-        &credVault::ID,
-    ).unwrap();
-    
-    // The mint_credential function requires the issuer to be verified
-    // In a real deployment, an admin would verify issuers
-    // For this test, we'll assume the issuer is already verified
-    
-    // Now test minting a credential
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
     let skill_name = "Web Development".to_string();
-    let issue_date = Clock::default().unix_timestamp;
+    let issue_date = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
     let credential_uri = "https://example.com/credentials/web-dev.json".to_string();
-    
-    // Create mint account
-    let credential_mint = Keypair::new();
-    let mint_rent = rent.minimum_balance(spl_token::state::Mint::LEN);
-    
-    let create_mint_ix = system_instruction::create_account(
-        &ctx.payer.pubkey(),
-        &credential_mint.pubkey(),
-        mint_rent,
-        spl_token::state::Mint::LEN as u64,
-        &spl_token::ID,
-    );
-    
-    let initialize_mint_ix = spl_token::instruction::initialize_mint(
-        &spl_token::ID,
-        &credential_mint.pubkey(),
-        &issuer.pubkey(),
-        Some(&issuer.pubkey()),
-        0,
-    ).unwrap();
-    
-    let mint_setup_tx = Transaction::new_signed_with_payer(
-        &[create_mint_ix, initialize_mint_ix],
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer, &credential_mint],
-        ctx.last_blockhash,
-    );
-    
-    ctx.banks_client.process_transaction(mint_setup_tx).await.unwrap();
-    
-    // Create the credential account
-    let credential_account_key = Pubkey::create_program_address(
+
+    // The credential, its mint and the program authority are all PDAs
+    let (credential_account_key, _) = Pubkey::find_program_address(
         &[
-            b"credential", 
-            student.pubkey().to_bytes().as_ref(), 
-            issuer.pubkey().to_bytes().as_ref(), 
-            skill_name.as_bytes()
+            b"credential",
+            student.pubkey().as_ref(),
+            issuer.pubkey().as_ref(),
+            skill_name.as_bytes(),
         ],
         &credVault::ID,
-    ).unwrap();
-    
-    // Create the mint credential instruction
+    );
+    let (credential_mint, _) = Pubkey::find_program_address(
+        &[b"mint", credential_account_key.as_ref()],
+        &credVault::ID,
+    );
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
+
     let accounts = credVault::accounts::MintCredential {
         credential_account: credential_account_key,
-        issuer: issuer_account_key, // This would be the verified issuer account
-        issuer_account: issuer.pubkey(),
+        issuer: issuer_account_key,
+        authority: issuer.pubkey(),
+        delegate: None,
+        multisig: None,
+        operation: None,
         student: student.pubkey(),
-        token_metadata: TODO: Implement proper metadata account, // This is a placeholder
-        credential_mint: credential_mint.pubkey(),
-        credential_token_account: TODO: Implement proper token account, // This is a placeholder
-        master_edition: TODO: Implement proper master edition account, // This is a placeholder
+        token_metadata: None,
+        credential_mint,
+        credential_token_account: spl_associated_token_account::get_associated_token_address_with_program_id(
+            &student.pubkey(),
+            &credential_mint,
+            &spl_token_2022::ID,
+        ),
+        program_authority,
         metadata_program: mpl_token_metadata::ID,
-        token_program: spl_token::ID,
+        token_program: spl_token_2022::ID,
         system_program: solana_sdk::system_program::ID,
         rent: sysvar::rent::ID,
         associated_token_program: spl_associated_token_account::ID,
     };
-    
+
     let data = credVault::instruction::MintCredential {
-        skill_name,
+        skill_name: skill_name.clone(),
         issue_date,
-        credential_uri,
+        credential_uri: credential_uri.clone(),
+        expires_at: None,
+        co_issuers: vec![],
     };
-    
+
     let instruction = Instruction {
         program_id: credVault::ID,
-        accounts: accounts.to_account_infos(), // This is a simplified version
+        accounts: accounts.to_account_metas(None),
         data: data.data(),
     };
-    
+
+    // The issuer signs to mint, and the student to accept the soulbound credential
     let tx = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&issuer.pubkey()),
-        &[&issuer], // The issuer signs to mint the credential
+        &[&issuer, &student],
         ctx.last_blockhash,
     );
-    
-    // This test would require more complex setup for the metadata accounts
-    // For brevity in this example, we focus on the core functionality
-    // In a full implementation, all accounts would be properly set up
-    
-    // The following line will likely fail without proper account setup
-    // ctx.banks_client.process_transaction(tx).await.unwrap();
-    
-    // Instead, we'll just verify that the instruction can be constructed
-    assert!(true); // Placeholder assertion
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let credential_account = ctx.banks_client.get_account(credential_account_key).await.unwrap().unwrap();
+    let credential_account =
+        credVault::CredentialAccount::try_deserialize(&mut credential_account.data.as_ref()).unwrap();
+    assert_eq!(credential_account.issuer_pubkey, issuer.pubkey());
+    assert_eq!(credential_account.student_pubkey, student.pubkey());
+    assert_eq!(credential_account.skill_name, skill_name);
+    assert_eq!(credential_account.issue_date, issue_date);
+    assert_eq!(credential_account.credential_uri, credential_uri);
+    assert!(credential_account.is_soulbound);
+    assert!(!credential_account.is_revoked);
+    assert_eq!(credential_account.token_kind, credVault::CredentialTokenKind::Token2022);
 }
 
 #[tokio::test]
@@ -235,124 +189,116 @@ async fn test_batch_issue_credential() {
         None,
     );
 
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
     let mut ctx = program_test.start_with_context().await;
-    
     let issuer = Keypair::new();
-    let student1 = Keypair::new();
-    let student2 = Keypair::new();
-
-    let rent = Rent::default();
-    let rent_lamports = rent.minimum_balance(0);
-
-    let fund_issuer_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &issuer.pubkey(),
-        rent_lamports.max(1_000_000),
-    );
-    let fund_student1_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &student1.pubkey(),
-        rent_lamports.max(1_000_000),
-    );
-    let fund_student2_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &student2.pubkey(),
-        rent_lamports.max(1_000_000),
-    );
-
-    let fund_tx = Transaction::new_signed_with_payer(
-        &[fund_issuer_ix, fund_student1_ix, fund_student2_ix],
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer],
-        ctx.last_blockhash,
-    );
-    ctx.banks_client.process_transaction(fund_tx).await.unwrap();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
 
-    // Simulate batch issuance by calling mint_credential multiple times
-    let credentials_to_mint = vec![
-        ("Rust Programming".to_string(), student1.pubkey()),
-        ("Solana Development".to_string(), student2.pubkey()),
-    ];
+    let skill_name = "Solana Development".to_string();
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
 
-    for (skill_name, student_pubkey) in credentials_to_mint {
-        let issue_date = Clock::default().unix_timestamp;
-        let credential_uri = format!("https://example.com/credentials/{}.json", skill_name.replace(" ", "-"));
-
-        let credential_mint = Keypair::new();
-        let mint_rent = rent.minimum_balance(spl_token::state::Mint::LEN);
-        
-        let create_mint_ix = system_instruction::create_account(
-            &ctx.payer.pubkey(),
-            &credential_mint.pubkey(),
-            mint_rent,
-            spl_token::state::Mint::LEN as u64,
-            &spl_token::ID,
-        );
-        
-        let initialize_mint_ix = spl_token::instruction::initialize_mint(
-            &spl_token::ID,
-            &credential_mint.pubkey(),
-            &issuer.pubkey(),
-            Some(&issuer.pubkey()),
-            0,
-        ).unwrap();
-        
-        let mint_setup_tx = Transaction::new_signed_with_payer(
-            &[create_mint_ix, initialize_mint_ix],
-            Some(&ctx.payer.pubkey()),
-            &[&ctx.payer, &credential_mint],
-            ctx.last_blockhash,
-        );
-        
-        ctx.banks_client.process_transaction(mint_setup_tx).await.unwrap();
-
-        let (credential_account_key, _bump) = Pubkey::find_program_address(
-            &[
-                b"credential", 
-                student_pubkey.to_bytes().as_ref(), 
-                issuer.pubkey().to_bytes().as_ref(), 
-                skill_name.as_bytes()
-            ],
+    // Token-2022 credentials need [student, credential, mint, token account] per student
+    let student_accounts = |student: &Pubkey| {
+        let (credential, _) = Pubkey::find_program_address(
+            &[b"credential", student.as_ref(), issuer.pubkey().as_ref(), skill_name.as_bytes()],
             &credVault::ID,
         );
+        let (mint, _) = Pubkey::find_program_address(&[b"mint", credential.as_ref()], &credVault::ID);
+        let token_account = spl_associated_token_account::get_associated_token_address_with_program_id(
+            student,
+            &mint,
+            &spl_token_2022::ID,
+        );
+        vec![
+            AccountMeta::new_readonly(*student, true),
+            AccountMeta::new(credential, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(token_account, false),
+        ]
+    };
 
-        let accounts = credVault::accounts::MintCredential {
-            credential_account: credential_account_key,
-            issuer: issuer.pubkey(),
-            token_metadata: Pubkey::new_unique(), // Placeholder
-            credential_mint: credential_mint.pubkey(),
-            credential_token_account: Pubkey::new_unique(), // Placeholder
-            master_edition: Pubkey::new_unique(), // Placeholder
+    let batch_ix = |students: &[Pubkey]| {
+        let mut accounts = credVault::accounts::BatchMintCredentials {
+            issuer: issuer_account_key,
+            authority: issuer.pubkey(),
+            delegate: None,
+            program_authority,
             metadata_program: mpl_token_metadata::ID,
-            token_program: spl_token::ID,
+            token_program: spl_token_2022::ID,
             system_program: solana_sdk::system_program::ID,
             rent: sysvar::rent::ID,
             associated_token_program: spl_associated_token_account::ID,
-        };
-        
-        let data = credVault::instruction::MintCredential {
-            credential_data: format!("{{\"skillName\":\"{}\",\"issueDate\":\"{}\",\"metadataUri\":\"{}\"}}", skill_name, issue_date, credential_uri),
-            credential_name: skill_name.clone(),
-            credential_symbol: "CRD".to_string(),
-            credential_uri: credential_uri.clone(),
-        };
-        
-        let instruction = Instruction {
+        }
+        .to_account_metas(None);
+        for student in students {
+            accounts.extend(student_accounts(student));
+        }
+        Instruction {
             program_id: credVault::ID,
-            accounts: accounts.to_account_infos(),
-            data: data.data(),
-        };
-        
-        let tx = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&issuer.pubkey()),
-            &[&issuer],
-            ctx.last_blockhash,
-        );
-        
-        // This will still likely fail due to missing metadata account setup
-        // For now, we assert that the instruction can be constructed
-        assert!(true);
+            accounts,
+            data: credVault::instruction::BatchMintCredentials {
+                skill_name: skill_name.clone(),
+                issue_date: Clock::default().unix_timestamp,
+                credential_uri: "https://example.com/credentials/solana-dev.json".to_string(),
+                expires_at: None,
+            }
+            .data(),
+        }
+    };
+
+    let student1 = Keypair::new();
+    let student2 = Keypair::new();
+    let student3 = Keypair::new();
+
+    // Students must consent to a soulbound credential, as with mint_credential
+    let mut unsigned_ix = batch_ix(&[student1.pubkey(), student2.pubkey()]);
+    unsigned_ix.accounts.iter_mut()
+        .filter(|meta| meta.pubkey == student2.pubkey())
+        .for_each(|meta| meta.is_signer = false);
+    let tx = Transaction::new_signed_with_payer(
+        &[unsigned_ix],
+        Some(&issuer.pubkey()),
+        &[&issuer, &student1],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::StudentSignatureRequired as u32 + 6000),
+        )
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix(&[student1.pubkey(), student2.pubkey()])],
+        Some(&issuer.pubkey()),
+        &[&issuer, &student1, &student2],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Re-issuing to the cohort skips student1 and mints for the newcomer
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix(&[student1.pubkey(), student3.pubkey()])],
+        Some(&issuer.pubkey()),
+        &[&issuer, &student1, &student3],
+        ctx.last_blockhash,
+    );
+    let simulation = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    let result = credVault::BatchMintResult::try_from_slice(&return_data.data).unwrap();
+    assert_eq!(result.minted, 1);
+    assert_eq!(result.skipped, vec![student1.pubkey()]);
+
+    for student in [student1.pubkey(), student2.pubkey()] {
+        let credential = ctx.banks_client.get_account(student_accounts(&student)[1].pubkey).await.unwrap().unwrap();
+        let credential = credVault::CredentialAccount::try_deserialize(&mut credential.data.as_ref()).unwrap();
+        assert_eq!(credential.student_pubkey, student);
+        assert_eq!(credential.token_kind, credVault::CredentialTokenKind::Token2022);
     }
 }
 
@@ -364,134 +310,3738 @@ async fn test_verify_credential() {
         None,
     );
 
-    let mut ctx = program_test.start_with_context().await;
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
 
+    let mut ctx = program_test.start_with_context().await;
     let issuer = Keypair::new();
     let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
 
-    let rent = Rent::default();
-    let rent_lamports = rent.minimum_balance(0);
+    let (credential_account_key, credential_mint) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Solana Basics", None).await;
 
-    let fund_issuer_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &issuer.pubkey(),
-        rent_lamports.max(1_000_000),
+    // Anyone can verify the credential against its issuer
+    let result =
+        simulate_verify_credential(&mut ctx, credential_account_key, issuer_account_key, Some(credential_mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.issuer_pubkey, issuer.pubkey());
+    assert!(result.issuer_verified);
+    assert_eq!(result.skill_name, "Solana Basics");
+    assert_eq!(result.revoked_at, None);
+}
+
+#[tokio::test]
+async fn test_update_metadata() {
+    // This test would verify that metadata can be updated by the issuer
+    // Implementation would create a credential and then update its metadata
+    assert!(true); // Placeholder assertion
+}
+
+#[tokio::test]
+async fn test_revoke_credential() {
+    // This test would verify that an issuer can revoke a credential
+    // Implementation would create a credential and then revoke it
+    assert!(true); // Placeholder assertion
+}
+
+// Inject the governance config directly, since ProgramTest loads the program
+// without upgradeable ProgramData for initialize_config to check against
+fn add_config_account(program_test: &mut ProgramTest, admins: Vec<Pubkey>, threshold: u8) -> Pubkey {
+    let (config_key, bump) = Pubkey::find_program_address(&[b"config"], &credVault::ID);
+    let config = credVault::Config {
+        admins,
+        threshold,
+        proposal_count: 0,
+        bump,
+        created_at: 0,
+    };
+
+    let mut data = Vec::new();
+    config.try_serialize(&mut data).unwrap();
+    data.resize(8 + 4 + 32 * credVault::MAX_ADMINS + 1 + 8 + 1 + 8, 0);
+
+    program_test.add_account(
+        config_key,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: credVault::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    let fund_student_ix = system_instruction::transfer(
-        &ctx.payer.pubkey(),
-        &student.pubkey(),
-        rent_lamports.max(1_000_000),
+    config_key
+}
+
+fn fund_ix(from: &Pubkey, to: &Pubkey) -> Instruction {
+    system_instruction::transfer(from, to, 1_000_000_000)
+}
+
+fn proposal_key(id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"proposal", id.to_le_bytes().as_ref()], &credVault::ID).0
+}
+
+#[tokio::test]
+async fn test_issuer_verification_requires_threshold() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
     );
 
+    let admin1 = Keypair::new();
+    let admin2 = Keypair::new();
+    let admin3 = Keypair::new();
+    let config_key = add_config_account(
+        &mut program_test,
+        vec![admin1.pubkey(), admin2.pubkey(), admin3.pubkey()],
+        2,
+    );
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+
     let fund_tx = Transaction::new_signed_with_payer(
-        &[fund_issuer_ix, fund_student_ix],
+        &[
+            fund_ix(&ctx.payer.pubkey(), &issuer.pubkey()),
+            fund_ix(&ctx.payer.pubkey(), &admin1.pubkey()),
+            fund_ix(&ctx.payer.pubkey(), &admin2.pubkey()),
+        ],
         Some(&ctx.payer.pubkey()),
         &[&ctx.payer],
         ctx.last_blockhash,
     );
     ctx.banks_client.process_transaction(fund_tx).await.unwrap();
 
-    let skill_name = "Solana Basics".to_string();
-    let issue_date = Clock::default().unix_timestamp;
-    let credential_uri = "https://example.com/credentials/solana-basics.json".to_string();
-
-    // Create mint account
-    let credential_mint = Keypair::new();
-    let mint_rent = rent.minimum_balance(spl_token::state::Mint::LEN);
-    
-    let create_mint_ix = system_instruction::create_account(
-        &ctx.payer.pubkey(),
-        &credential_mint.pubkey(),
-        mint_rent,
-        spl_token::state::Mint::LEN as u64,
-        &spl_token::ID,
+    // Register the issuer
+    let (issuer_account_key, bump) = Pubkey::find_program_address(
+        &[b"issuer", issuer.pubkey().as_ref()],
+        &credVault::ID,
     );
-    
-    let initialize_mint_ix = spl_token::instruction::initialize_mint(
-        &spl_token::ID,
-        &credential_mint.pubkey(),
-        &issuer.pubkey(),
-        Some(&issuer.pubkey()),
-        0,
-    ).unwrap();
-    
-    let mint_setup_tx = Transaction::new_signed_with_payer(
-        &[create_mint_ix, initialize_mint_ix],
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer, &credential_mint],
+    let init_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::InitializeIssuer {
+            issuer_account: issuer_account_key,
+            authority: issuer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::InitializeIssuer { bump }.data(),
+    };
+
+    // Admin 1 proposes verifying the issuer
+    let proposal = proposal_key(0);
+    let propose_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ProposeIssuerStatus {
+            config: config_key,
+            proposal,
+            issuer_account: issuer_account_key,
+            proposer: admin1.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ProposeIssuerStatus { status: credVault::IssuerStatus::Verified }.data(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix, propose_ix],
+        Some(&admin1.pubkey()),
+        &[&admin1, &issuer],
         ctx.last_blockhash,
     );
-    
-    ctx.banks_client.process_transaction(mint_setup_tx).await.unwrap();
+    ctx.banks_client.process_transaction(tx).await.unwrap();
 
-    let (credential_account_key, _bump) = Pubkey::find_program_address(
-        &[
-            b"credential", 
-            student.pubkey().to_bytes().as_ref(), 
-            issuer.pubkey().to_bytes().as_ref(), 
-            skill_name.as_bytes()
-        ],
-        &credVault::ID,
+    let execute_ix = |executor: Pubkey| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ExecuteProposal {
+            config: config_key,
+            proposal,
+            issuer_account: Some(issuer_account_key),
+            application: None,
+            issuer_name: None,
+            issuer_stake: None,
+            recipient: None,
+            treasury: None,
+            fraud_report: None,
+            credential_account: None,
+            appeal: None,
+            executor,
+            system_program: None,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ExecuteProposal {}.data(),
+    };
+
+    // One approval is not enough for a 2-of-3 config
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix(admin1.pubkey())],
+        Some(&admin1.pubkey()),
+        &[&admin1],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::InsufficientApprovals as u32 + 6000),
+        )
     );
 
-    let accounts = credVault::accounts::MintCredential {
-        credential_account: credential_account_key,
-        issuer: issuer.pubkey(),
-        token_metadata: Pubkey::new_unique(), // Placeholder
-        credential_mint: credential_mint.pubkey(),
-        credential_token_account: Pubkey::new_unique(), // Placeholder
-        master_edition: Pubkey::new_unique(), // Placeholder
-        metadata_program: mpl_token_metadata::ID,
-        token_program: spl_token::ID,
-        system_program: solana_sdk::system_program::ID,
-        rent: sysvar::rent::ID,
-        associated_token_program: spl_associated_token_account::ID,
-    };
-    
-    let data = credVault::instruction::MintCredential {
-        credential_data: format!("{{\"skillName\":\"{}\",\"issueDate\":\"{}\",\"metadataUri\":\"{}\"}}", skill_name, issue_date, credential_uri),
-        credential_name: skill_name.clone(),
-        credential_symbol: "CRD".to_string(),
-        credential_uri: credential_uri.clone(),
-    };
-    
-    let instruction = Instruction {
+    // A second admin approves, then the proposal can execute
+    let approve_ix = Instruction {
         program_id: credVault::ID,
-        accounts: accounts.to_account_infos(),
-        data: data.data(),
+        accounts: credVault::accounts::ApproveProposal {
+            config: config_key,
+            proposal,
+            approver: admin2.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ApproveProposal {}.data(),
     };
-    
     let tx = Transaction::new_signed_with_payer(
-        &[instruction],
-        Some(&issuer.pubkey()),
-        &[&issuer],
+        &[approve_ix, execute_ix(admin2.pubkey())],
+        Some(&admin2.pubkey()),
+        &[&admin2],
         ctx.last_blockhash,
     );
-    
-    // Execute the minting transaction
     ctx.banks_client.process_transaction(tx).await.unwrap();
 
-    // Now, attempt to fetch the credential account to verify its existence
-    let fetched_credential_account = ctx.banks_client.get_account(credential_account_key).await.unwrap().unwrap();
-    assert!(fetched_credential_account.data.len() > 0);
-
-    // Deserialize the account data to check its contents (optional, but good practice)
-    let deserialized_account: credVault::CredentialAccount = credVault::CredentialAccount::try_deserialize(&mut fetched_credential_account.data.as_ref()).unwrap();
-    assert_eq!(deserialized_account.owner, student.pubkey());
-    assert_eq!(deserialized_account.issuer, issuer.pubkey());
-    // Further assertions can be made on credentialData, issuedAt, etc.
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    assert_eq!(issuer_account.status, credVault::IssuerStatus::Verified);
+    assert!(issuer_account.verified_at.is_some());
 }
 
 #[tokio::test]
-async fn test_update_metadata() {
-    // This test would verify that metadata can be updated by the issuer
-    // Implementation would create a credential and then update its metadata
-    assert!(true); // Placeholder assertion
-}
+async fn test_config_is_initialized_by_upgrade_authority_and_governs_issuers() {
+    let mut program_test = ProgramTest::default();
+    program_test.add_upgradeable_program_to_genesis("credVault", &credVault::ID);
+    let mut ctx = program_test.start_with_context().await;
 
-#[tokio::test]
-async fn test_revoke_credential() {
-    // This test would verify that an issuer can revoke a credential
-    // Implementation would create a credential and then revoke it
-    assert!(true); // Placeholder assertion
-}
\ No newline at end of file
+    let deployer = Keypair::new();
+    let outsider = Keypair::new();
+    let admin = Keypair::new();
+    let issuer = Keypair::new();
+    let fund_tx = Transaction::new_signed_with_payer(
+        &[
+            fund_ix(&ctx.payer.pubkey(), &deployer.pubkey()),
+            fund_ix(&ctx.payer.pubkey(), &outsider.pubkey()),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(fund_tx).await.unwrap();
+
+    // Hand the upgrade authority to the deployer: ProgramData is a u32 tag,
+    // a u64 slot and an Option<Pubkey> upgrade authority
+    let program_data = Pubkey::find_program_address(
+        &[credVault::ID.as_ref()],
+        &solana_sdk::bpf_loader_upgradeable::ID,
+    )
+    .0;
+    let mut program_data_account = ctx.banks_client.get_account(program_data).await.unwrap().unwrap();
+    program_data_account.data[12] = 1;
+    program_data_account.data[13..45].copy_from_slice(deployer.pubkey().as_ref());
+    ctx.set_account(&program_data, &program_data_account.into());
+
+    let config_key = Pubkey::find_program_address(&[b"config"], &credVault::ID).0;
+    let init_config_ix = |authority: Pubkey, admins: Vec<Pubkey>, threshold: u8| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::InitializeConfig {
+            config: config_key,
+            authority,
+            program: credVault::ID,
+            program_data,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::InitializeConfig { admins, threshold }.data(),
+    };
+
+    // Only the upgrade authority can set up governance
+    let tx = Transaction::new_signed_with_payer(
+        &[init_config_ix(outsider.pubkey(), vec![outsider.pubkey()], 1)],
+        Some(&outsider.pubkey()),
+        &[&outsider],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::UnauthorizedAdmin as u32 + 6000),
+        )
+    );
+
+    // Duplicate admins would let one key count twice towards the threshold
+    let tx = Transaction::new_signed_with_payer(
+        &[init_config_ix(deployer.pubkey(), vec![admin.pubkey(), admin.pubkey()], 2)],
+        Some(&deployer.pubkey()),
+        &[&deployer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::DuplicateAdmin as u32 + 6000),
+        )
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[init_config_ix(deployer.pubkey(), vec![admin.pubkey()], 1)],
+        Some(&deployer.pubkey()),
+        &[&deployer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let config = ctx.banks_client.get_account(config_key).await.unwrap().unwrap();
+    let config = credVault::Config::try_deserialize(&mut config.data.as_ref()).unwrap();
+    assert_eq!(config.admins, vec![admin.pubkey()]);
+    assert_eq!(config.threshold, 1);
+    assert_eq!(config.proposal_count, 0);
+
+    // The new config verifies an issuer, then moves it back to pending
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    let proposal = proposal_key(1);
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::ProposeIssuerStatus {
+                    config: config_key,
+                    proposal,
+                    issuer_account: issuer_account_key,
+                    proposer: admin.pubkey(),
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::ProposeIssuerStatus { status: credVault::IssuerStatus::Pending }.data(),
+            },
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::ExecuteProposal {
+                    config: config_key,
+                    proposal,
+                    issuer_account: Some(issuer_account_key),
+                    application: None,
+                    issuer_name: None,
+                    issuer_stake: None,
+                    recipient: None,
+                    treasury: None,
+                    fraud_report: None,
+                    credential_account: None,
+                    appeal: None,
+                    executor: admin.pubkey(),
+                    system_program: None,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::ExecuteProposal {}.data(),
+            },
+        ],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    assert_eq!(issuer_account.status, credVault::IssuerStatus::Pending);
+}
+
+// Register an issuer and verify it through a single-admin proposal
+async fn setup_verified_issuer(
+    ctx: &mut ProgramTestContext,
+    config_key: Pubkey,
+    admin: &Keypair,
+    issuer: &Keypair,
+) -> Pubkey {
+    setup_verified_issuer_with_proposal(ctx, config_key, admin, issuer, 0).await
+}
+
+// Like setup_verified_issuer, for verifying further issuers through later proposals
+async fn setup_verified_issuer_with_proposal(
+    ctx: &mut ProgramTestContext,
+    config_key: Pubkey,
+    admin: &Keypair,
+    issuer: &Keypair,
+    proposal_id: u64,
+) -> Pubkey {
+    let (issuer_account_key, bump) = Pubkey::find_program_address(
+        &[b"issuer", issuer.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let proposal = proposal_key(proposal_id);
+
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &issuer.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &admin.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::InitializeIssuer {
+                issuer_account: issuer_account_key,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::InitializeIssuer { bump }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeIssuerStatus {
+                config: config_key,
+                proposal,
+                issuer_account: issuer_account_key,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeIssuerStatus { status: credVault::IssuerStatus::Verified }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: Some(issuer_account_key),
+                application: None,
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, issuer, admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+    issuer_account_key
+}
+
+#[tokio::test]
+async fn test_soulbound_credential_cannot_be_transferred() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+    program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    let skill_name = "Rust Programming".to_string();
+    let (credential_account_key, _) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            student.pubkey().as_ref(),
+            issuer.pubkey().as_ref(),
+            skill_name.as_bytes(),
+        ],
+        &credVault::ID,
+    );
+    let (credential_mint, _) = Pubkey::find_program_address(
+        &[b"mint", credential_account_key.as_ref()],
+        &credVault::ID,
+    );
+    let credential_token_account = spl_associated_token_account::get_associated_token_address(
+        &student.pubkey(),
+        &credential_mint,
+    );
+    let (token_metadata, _) = Pubkey::find_program_address(
+        &[b"metadata", mpl_token_metadata::ID.as_ref(), credential_mint.as_ref()],
+        &mpl_token_metadata::ID,
+    );
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
+
+    let mint_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::MintCredential {
+            credential_account: credential_account_key,
+            issuer: issuer_account_key,
+            authority: issuer.pubkey(),
+            delegate: None,
+            multisig: None,
+            operation: None,
+            student: student.pubkey(),
+            token_metadata: Some(token_metadata),
+            credential_mint,
+            credential_token_account,
+            program_authority,
+            metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: spl_associated_token_account::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::MintCredential {
+            skill_name,
+            issue_date: Clock::default().unix_timestamp,
+            credential_uri: "https://example.com/credentials/rust.json".to_string(),
+            expires_at: None,
+            co_issuers: vec![],
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&issuer.pubkey()),
+        &[&issuer, &student],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Set up a destination token account for someone else
+    let recipient = Keypair::new();
+    let destination = Keypair::new();
+    let account_rent = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+    let create_destination_ix = system_instruction::create_account(
+        &ctx.payer.pubkey(),
+        &destination.pubkey(),
+        account_rent,
+        spl_token::state::Account::LEN as u64,
+        &spl_token::ID,
+    );
+    let init_destination_ix = spl_token::instruction::initialize_account(
+        &spl_token::ID,
+        &destination.pubkey(),
+        &credential_mint,
+        &recipient.pubkey(),
+    ).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_destination_ix, init_destination_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &destination],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The student holds the credential but cannot move it
+    let transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &credential_token_account,
+        &destination.pubkey(),
+        &student.pubkey(),
+        &[],
+        1,
+    ).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[fund_ix(&ctx.payer.pubkey(), &student.pubkey()), transfer_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &student],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(spl_token::error::TokenError::AccountFrozen as u32),
+        )
+    );
+
+    let token_account = ctx.banks_client.get_account(credential_token_account).await.unwrap().unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+    assert_eq!(token_account.owner, student.pubkey());
+    assert!(token_account.is_frozen());
+    assert_eq!(token_account.amount, 1);
+
+    // Nobody outside the program can thaw it: the program PDA stays the freeze authority
+    let mint_account = ctx.banks_client.get_account(credential_mint).await.unwrap().unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint_account.freeze_authority, Some(program_authority).into());
+    assert_eq!(mint_account.mint_authority, Some(program_authority).into());
+    let metadata_account = ctx.banks_client.get_account(token_metadata).await.unwrap().unwrap();
+    let metadata = mpl_token_metadata::accounts::Metadata::from_bytes(&metadata_account.data).unwrap();
+    assert_eq!(metadata.update_authority, program_authority);
+
+    for thawer in [&student, &issuer] {
+        let thaw_ix = spl_token::instruction::thaw_account(
+            &spl_token::ID,
+            &credential_token_account,
+            &credential_mint,
+            &thawer.pubkey(),
+            &[],
+        ).unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[thaw_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, thawer],
+            ctx.last_blockhash,
+        );
+        let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(spl_token::error::TokenError::OwnerMismatch as u32),
+            )
+        );
+    }
+
+    let token_account = ctx.banks_client.get_account(credential_token_account).await.unwrap().unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+    assert!(token_account.is_frozen());
+}
+
+// Mint a Token-2022 credential, returning the credential account and mint
+async fn mint_token_2022_credential(
+    ctx: &mut ProgramTestContext,
+    issuer_account_key: Pubkey,
+    issuer: &Keypair,
+    student: &Keypair,
+    skill_name: &str,
+    expires_at: Option<i64>,
+) -> (Pubkey, Pubkey) {
+    mint_co_issued_token_2022_credential(ctx, issuer_account_key, issuer, student, skill_name, expires_at, vec![]).await
+}
+
+async fn mint_co_issued_token_2022_credential(
+    ctx: &mut ProgramTestContext,
+    issuer_account_key: Pubkey,
+    issuer: &Keypair,
+    student: &Keypair,
+    skill_name: &str,
+    expires_at: Option<i64>,
+    co_issuers: Vec<Pubkey>,
+) -> (Pubkey, Pubkey) {
+    let (credential_account_key, _) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            student.pubkey().as_ref(),
+            issuer.pubkey().as_ref(),
+            skill_name.as_bytes(),
+        ],
+        &credVault::ID,
+    );
+    let (credential_mint, _) = Pubkey::find_program_address(
+        &[b"mint", credential_account_key.as_ref()],
+        &credVault::ID,
+    );
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
+
+    let now = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let mint_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::MintCredential {
+            credential_account: credential_account_key,
+            issuer: issuer_account_key,
+            authority: issuer.pubkey(),
+            delegate: None,
+            multisig: None,
+            operation: None,
+            student: student.pubkey(),
+            token_metadata: None,
+            credential_mint,
+            credential_token_account: spl_associated_token_account::get_associated_token_address_with_program_id(
+                &student.pubkey(),
+                &credential_mint,
+                &spl_token_2022::ID,
+            ),
+            program_authority,
+            metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token_2022::ID,
+            system_program: solana_sdk::system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: spl_associated_token_account::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::MintCredential {
+            skill_name: skill_name.to_string(),
+            issue_date: now,
+            credential_uri: "https://example.com/credentials/first-aid.json".to_string(),
+            expires_at,
+            co_issuers,
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&issuer.pubkey()),
+        &[issuer, student],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+    (credential_account_key, credential_mint)
+}
+
+// Simulate verify_credential and decode its return data
+async fn simulate_verify_credential(
+    ctx: &mut ProgramTestContext,
+    credential_account: Pubkey,
+    issuer_account: Pubkey,
+    credential_mint: Option<Pubkey>,
+) -> credVault::VerificationResult {
+    simulate_verify_credential_with_accounts(ctx, credential_account, issuer_account, credential_mint, &[]).await
+}
+
+// `extra_accounts` are the co-issuer accounts followed by the issuer's accreditors
+async fn simulate_verify_credential_with_accounts(
+    ctx: &mut ProgramTestContext,
+    credential_account: Pubkey,
+    issuer_account: Pubkey,
+    credential_mint: Option<Pubkey>,
+    extra_accounts: &[Pubkey],
+) -> credVault::VerificationResult {
+    let mut accounts = credVault::accounts::VerifyCredential {
+        credential_account,
+        issuer: issuer_account,
+        credential_mint,
+        registry: None,
+        registry_entry: None,
+        issuer_name: None,
+        issuer_stake: None,
+    }
+    .to_account_metas(None);
+    accounts.extend(extra_accounts.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    simulate_verification(ctx, accounts).await
+}
+
+// Verify against a trust registry, passing the issuer's entry PDA whether or not it exists
+async fn simulate_verify_credential_in_registry(
+    ctx: &mut ProgramTestContext,
+    credential_account: Pubkey,
+    issuer_account: Pubkey,
+    issuer_pubkey: Pubkey,
+    registry: Pubkey,
+) -> credVault::VerificationResult {
+    let (registry_entry, _) = Pubkey::find_program_address(
+        &[b"registry-entry", registry.as_ref(), issuer_pubkey.as_ref()],
+        &credVault::ID,
+    );
+    let accounts = credVault::accounts::VerifyCredential {
+        credential_account,
+        issuer: issuer_account,
+        credential_mint: None,
+        registry: Some(registry),
+        registry_entry: Some(registry_entry),
+        issuer_name: None,
+        issuer_stake: None,
+    }
+    .to_account_metas(None);
+    simulate_verification(ctx, accounts).await
+}
+
+async fn simulate_verification(
+    ctx: &mut ProgramTestContext,
+    accounts: Vec<AccountMeta>,
+) -> credVault::VerificationResult {
+    let ix = Instruction {
+        program_id: credVault::ID,
+        accounts,
+        data: credVault::instruction::VerifyCredential {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&ctx.payer.pubkey()), &[&ctx.payer], ctx.last_blockhash);
+    let simulation = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    credVault::VerificationResult::try_from_slice(&return_data.data).unwrap()
+}
+
+async fn warp_clock_to(ctx: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    ctx.set_sysvar(&clock);
+}
+
+#[tokio::test]
+async fn test_program_pda_holds_every_credential_authority() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (_, mint) = mint_token_2022_credential(
+        &mut ctx,
+        issuer_account_key,
+        &issuer,
+        &student,
+        "First Aid",
+        None,
+    )
+    .await;
+
+    // Neither the issuer nor the student holds any authority over the credential token
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
+    let mint_account = ctx.banks_client.get_account(mint).await.unwrap().unwrap();
+    let mint_state = spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(
+        &mint_account.data,
+    )
+    .unwrap();
+    assert_eq!(mint_state.base.mint_authority, Some(program_authority).into());
+    assert_eq!(mint_state.base.freeze_authority, Some(program_authority).into());
+
+    use spl_token_2022::extension::BaseStateWithExtensions;
+    let metadata = mint_state
+        .get_variable_len_extension::<spl_token_metadata_interface::state::TokenMetadata>()
+        .unwrap();
+    assert_eq!(metadata.update_authority, Some(program_authority).try_into().unwrap());
+    assert_eq!(metadata.mint, mint);
+}
+
+#[tokio::test]
+async fn test_verify_credential_reports_status() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    // A Token-2022 credential valid for one year
+    let now = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let expires_at = now + 365 * 24 * 60 * 60;
+    let (credential, mint) = mint_token_2022_credential(
+        &mut ctx,
+        issuer_account_key,
+        &issuer,
+        &student,
+        "First Aid",
+        Some(expires_at),
+    )
+    .await;
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, Some(mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.issuer_pubkey, issuer.pubkey());
+    assert!(result.issuer_verified);
+    assert_eq!(result.skill_name, "First Aid");
+    assert_eq!(result.expires_at, Some(expires_at));
+
+    // Past the expiry the credential reports Expired rather than Revoked
+    warp_clock_to(&mut ctx, expires_at).await;
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, Some(mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Expired);
+    assert_eq!(result.revoked_at, None);
+}
+
+#[tokio::test]
+async fn test_expired_credential_is_renewed_through_mint_authorization() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    // A Token-2022 credential valid for 30 days
+    let now = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let expires_at = now + 30 * 24 * 60 * 60;
+    let (credential, mint) = mint_token_2022_credential(
+        &mut ctx,
+        issuer_account_key,
+        &issuer,
+        &student,
+        "First Aid",
+        Some(expires_at),
+    )
+    .await;
+
+    // One staff member may only revoke, the other may mint "First Aid" credentials
+    let revoker = Keypair::new();
+    let registrar = Keypair::new();
+    let delegate_key = |delegate: &Keypair| {
+        Pubkey::find_program_address(
+            &[b"delegate", issuer_account_key.as_ref(), delegate.pubkey().as_ref()],
+            &credVault::ID,
+        )
+        .0
+    };
+    let add_delegate_ix = |delegate: &Keypair, permissions: u8| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::AddIssuerDelegate {
+            issuer_account: issuer_account_key,
+            delegate: delegate_key(delegate),
+            delegate_key: delegate.pubkey(),
+            authority: issuer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::AddIssuerDelegate {
+            permissions,
+            allowed_skills: vec!["First Aid".to_string()],
+            mint_quota: None,
+            quota_period: 0,
+            expires_at: None,
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            add_delegate_ix(&revoker, credVault::IssuerDelegate::REVOKE),
+            add_delegate_ix(&registrar, credVault::IssuerDelegate::MINT),
+        ],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    warp_clock_to(&mut ctx, expires_at).await;
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, Some(mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Expired);
+
+    let renew_ix = |signer: Pubkey, delegate: Option<Pubkey>, new_expires_at: i64| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RenewCredential {
+            credential_account: credential,
+            issuer_account: issuer_account_key,
+            issuer: signer,
+            delegate,
+            multisig: None,
+            operation: None,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RenewCredential { new_expires_at: Some(new_expires_at) }.data(),
+    };
+    let renewed_until = expires_at + 365 * 24 * 60 * 60;
+
+    // Renewal needs mint permission, like issuing the credential in the first place
+    let tx = Transaction::new_signed_with_payer(
+        &[renew_ix(revoker.pubkey(), Some(delegate_key(&revoker)), renewed_until)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &revoker],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::UnauthorizedIssuer as u32 + 6000),
+        )
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[renew_ix(registrar.pubkey(), Some(delegate_key(&registrar)), renewed_until)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &registrar],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, Some(mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.expires_at, Some(renewed_until));
+
+    // Once the issuer's own verification lapses it can no longer extend credentials
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    warp_clock_to(&mut ctx, issuer_account.verified_until.unwrap()).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[renew_ix(issuer.pubkey(), None, renewed_until + 1)],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::VerificationLapsed as u32 + 6000),
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_issuer_suspension_only_affects_later_credentials() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    let start = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let early_student = Keypair::new();
+    let (early_credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &early_student, "Welding", None).await;
+
+    // The investigation finds fake certificates sold from `suspended_from` onwards
+    let suspended_from = start + 1000;
+    warp_clock_to(&mut ctx, suspended_from).await;
+    let late_student = Keypair::new();
+    let (late_credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &late_student, "Welding", None).await;
+
+    warp_clock_to(&mut ctx, suspended_from + 1000).await;
+    let suspend_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::SuspendIssuer {
+            config: config_key,
+            issuer_account: issuer_account_key,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::SuspendIssuer { effective_at: Some(suspended_from) }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[suspend_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, early_credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.issuer_status, credVault::IssuerStatus::Suspended);
+
+    let result = simulate_verify_credential(&mut ctx, late_credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::IssuerSuspended);
+}
+
+#[tokio::test]
+async fn test_verify_credential_reflects_issuer_revocation() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    let (credential, mint) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Forklift", None).await;
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, Some(mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+
+    // The issuer is caught selling fake certificates and revoked
+    let proposal = proposal_key(1);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeIssuerStatus {
+                config: config_key,
+                proposal,
+                issuer_account: issuer_account_key,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeIssuerStatus { status: credVault::IssuerStatus::Revoked }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: Some(issuer_account_key),
+                application: None,
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, Some(mint)).await;
+    assert_eq!(result.status, credVault::CredentialStatus::IssuerRevoked);
+    assert!(!result.issuer_verified);
+}
+
+#[tokio::test]
+async fn test_verification_application_flow() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+
+    let (issuer_account_key, bump) = Pubkey::find_program_address(
+        &[b"issuer", issuer.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &credVault::ID);
+    let (issuer_stake, _) =
+        Pubkey::find_program_address(&[b"stake", issuer_account_key.as_ref()], &credVault::ID);
+    let application_key = |id: u32| {
+        Pubkey::find_program_address(
+            &[b"application", issuer_account_key.as_ref(), id.to_le_bytes().as_ref()],
+            &credVault::ID,
+        )
+        .0
+    };
+    let apply_ix = |id: u32, stake: u64| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ApplyForVerification {
+            issuer_account: issuer_account_key,
+            application: application_key(id),
+            treasury,
+            issuer_stake,
+            authority: issuer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ApplyForVerification {
+            organization_name: "Lagos Coding Academy".to_string(),
+            document_uri: "https://example.com/registration.pdf".to_string(),
+            document_hash: [7; 32],
+            fee: None,
+            stake,
+        }
+        .data(),
+    };
+
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &issuer.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &admin.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::InitializeIssuer {
+                issuer_account: issuer_account_key,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::InitializeIssuer { bump }.data(),
+        },
+        apply_ix(0, credVault::MIN_ISSUER_STAKE),
+        // The first application is rejected for missing documents, through a proposal
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeApplicationRejection {
+                config: config_key,
+                proposal: proposal_key(0),
+                application: application_key(0),
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeApplicationRejection { reason_code: 2 }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal: proposal_key(0),
+                issuer_account: Some(issuer_account_key),
+                application: Some(application_key(0)),
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+        // The stake bonded with the first application carries over
+        apply_ix(1, 0),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer, &admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The second application is approved through a proposal
+    let proposal = proposal_key(1);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeApplicationApproval {
+                config: config_key,
+                proposal,
+                application: application_key(1),
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeApplicationApproval { reason_code: 1 }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: Some(issuer_account_key),
+                application: Some(application_key(1)),
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let rejected = ctx.banks_client.get_account(application_key(0)).await.unwrap().unwrap();
+    let rejected = credVault::VerificationApplication::try_deserialize(&mut rejected.data.as_ref()).unwrap();
+    assert_eq!(rejected.status, credVault::ApplicationStatus::Rejected);
+    assert_eq!(rejected.reason_code, Some(2));
+    assert_eq!(rejected.decided_by, Some(admin.pubkey()));
+    assert_eq!(rejected.proposal_id, Some(0));
+
+    let approved = ctx.banks_client.get_account(application_key(1)).await.unwrap().unwrap();
+    let approved = credVault::VerificationApplication::try_deserialize(&mut approved.data.as_ref()).unwrap();
+    assert_eq!(approved.status, credVault::ApplicationStatus::Approved);
+    assert_eq!(approved.proposal_id, Some(1));
+    assert_eq!(approved.document_hash, [7; 32]);
+    assert_eq!(approved.stake, credVault::MIN_ISSUER_STAKE);
+
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    assert_eq!(issuer_account.status, credVault::IssuerStatus::Verified);
+    assert_eq!(issuer_account.application_count, 2);
+    assert!(!issuer_account.has_open_application);
+}
+
+#[tokio::test]
+async fn test_treasury_pays_out_only_through_governance() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin1 = Keypair::new();
+    let admin2 = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin1.pubkey(), admin2.pubkey()], 2);
+
+    let mut ctx = program_test.start_with_context().await;
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &credVault::ID);
+    let recipient = Pubkey::new_unique();
+
+    // Application fees and forfeited bonds accumulate in the treasury
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            fund_ix(&ctx.payer.pubkey(), &treasury),
+            fund_ix(&ctx.payer.pubkey(), &admin1.pubkey()),
+            fund_ix(&ctx.payer.pubkey(), &admin2.pubkey()),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let propose_ix = |id: u64, amount: u64| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ProposeTreasuryWithdrawal {
+            config: config_key,
+            proposal: proposal_key(id),
+            treasury,
+            proposer: admin1.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ProposeTreasuryWithdrawal { amount, recipient }.data(),
+    };
+
+    // The treasury must stay rent-exempt
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(0, 1_000_000_000)],
+        Some(&admin1.pubkey()),
+        &[&admin1],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::InsufficientTreasuryBalance as u32 + 6000),
+        )
+    );
+
+    let amount = 400_000_000;
+    let execute_ix = |executor: Pubkey| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ExecuteProposal {
+            config: config_key,
+            proposal: proposal_key(0),
+            issuer_account: None,
+            application: None,
+            issuer_name: None,
+            issuer_stake: None,
+            recipient: Some(recipient),
+            treasury: Some(treasury),
+            fraud_report: None,
+            credential_account: None,
+            appeal: None,
+            executor,
+            system_program: Some(solana_sdk::system_program::ID),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ExecuteProposal {}.data(),
+    };
+
+    // A single admin cannot pay out on their own
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(0, amount), execute_ix(admin1.pubkey())],
+        Some(&admin1.pubkey()),
+        &[&admin1],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(credVault::CredVaultError::InsufficientApprovals as u32 + 6000),
+        )
+    );
+
+    let approve_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ApproveProposal {
+            config: config_key,
+            proposal: proposal_key(0),
+            approver: admin2.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ApproveProposal {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(0, amount), approve_ix, execute_ix(admin2.pubkey())],
+        Some(&admin2.pubkey()),
+        &[&admin1, &admin2],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let recipient_balance = ctx.banks_client.get_balance(recipient).await.unwrap();
+    assert_eq!(recipient_balance, amount);
+    let treasury_balance = ctx.banks_client.get_balance(treasury).await.unwrap();
+    assert_eq!(treasury_balance, 1_000_000_000 - amount);
+}
+
+#[tokio::test]
+async fn test_verified_issuer_profile_change_requires_approval() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    let profile = credVault::IssuerProfile {
+        display_name: "Lagos Coding Academy".to_string(),
+        website: "https://lagoscoding.example".to_string(),
+        logo_uri: "https://lagoscoding.example/logo.png".to_string(),
+        country_code: "NG".to_string(),
+        registration_number: "RC-123456".to_string(),
+        contact_uri: "mailto:registrar@lagoscoding.example".to_string(),
+    };
+    let update_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::UpdateIssuerProfile {
+            issuer_account: issuer_account_key,
+            authority: issuer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::UpdateIssuerProfile { profile: profile.clone() }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // The change is staged; verifiers still see the approved (empty) profile
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    assert_eq!(issuer_account.profile, credVault::IssuerProfile::default());
+    assert_eq!(issuer_account.pending_profile, Some(profile.clone()));
+    assert_eq!(issuer_account.status, credVault::IssuerStatus::Verified);
+
+    let proposal = proposal_key(1);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeProfileApproval {
+                config: config_key,
+                proposal,
+                issuer_account: issuer_account_key,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeProfileApproval {}.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: Some(issuer_account_key),
+                application: None,
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    assert_eq!(issuer_account.profile, profile);
+    assert_eq!(issuer_account.pending_profile, None);
+}
+
+#[tokio::test]
+async fn test_rotated_issuer_key_keeps_credentials_revocable() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Nursing", None).await;
+
+    // The registrar leaves and the institution moves to a new key
+    let new_authority = Keypair::new();
+    let rotate_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RotateIssuerAuthority {
+            issuer_account: issuer_account_key,
+            authority: issuer.pubkey(),
+            new_authority: new_authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RotateIssuerAuthority {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[rotate_ix],
+        Some(&issuer.pubkey()),
+        &[&issuer, &new_authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let revoke_ix = |signer: Pubkey| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RevokeCredential {
+            credential_account: credential,
+            issuer_account: issuer_account_key,
+            issuer: signer,
+            delegate: None,
+            multisig: None,
+            operation: None,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RevokeCredential {
+            reason_code: 1,
+            reason_uri: "https://example.com/revocations/1.json".to_string(),
+        }
+        .data(),
+    };
+
+    // The old key no longer controls the issuer
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix(issuer.pubkey())],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::UnauthorizedIssuer as u32 + 6000),
+        )
+    );
+
+    // The new key can revoke credentials issued under the old one, paying for the history entry
+    let tx = Transaction::new_signed_with_payer(
+        &[fund_ix(&ctx.payer.pubkey(), &new_authority.pubkey()), revoke_ix(new_authority.pubkey())],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &new_authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let credential = ctx.banks_client.get_account(credential).await.unwrap().unwrap();
+    let credential = credVault::CredentialAccount::try_deserialize(&mut credential.data.as_ref()).unwrap();
+    assert_eq!(credential.issuer_pubkey, issuer.pubkey());
+    assert!(credential.is_revoked);
+}
+
+#[tokio::test]
+async fn test_delegate_mint_respects_skill_and_quota() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    // An instructor may mint one "Welding" credential per day
+    let instructor = Keypair::new();
+    let (delegate_key, _) = Pubkey::find_program_address(
+        &[b"delegate", issuer_account_key.as_ref(), instructor.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &instructor.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::AddIssuerDelegate {
+                issuer_account: issuer_account_key,
+                delegate: delegate_key,
+                delegate_key: instructor.pubkey(),
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::AddIssuerDelegate {
+                permissions: credVault::IssuerDelegate::MINT,
+                allowed_skills: vec!["Welding".to_string()],
+                mint_quota: Some(1),
+                quota_period: 24 * 60 * 60,
+                expires_at: None,
+            }
+            .data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
+    let mint_ix = |student: &Pubkey, skill_name: &str| {
+        let (credential_account, _) = Pubkey::find_program_address(
+            &[b"credential", student.as_ref(), issuer.pubkey().as_ref(), skill_name.as_bytes()],
+            &credVault::ID,
+        );
+        let (credential_mint, _) =
+            Pubkey::find_program_address(&[b"mint", credential_account.as_ref()], &credVault::ID);
+        let ix = Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::MintCredential {
+                credential_account,
+                issuer: issuer_account_key,
+                authority: instructor.pubkey(),
+                delegate: Some(delegate_key),
+                multisig: None,
+                operation: None,
+                student: *student,
+                token_metadata: None,
+                credential_mint,
+                credential_token_account: spl_associated_token_account::get_associated_token_address_with_program_id(
+                    student,
+                    &credential_mint,
+                    &spl_token_2022::ID,
+                ),
+                program_authority,
+                metadata_program: mpl_token_metadata::ID,
+                token_program: spl_token_2022::ID,
+                system_program: solana_sdk::system_program::ID,
+                rent: sysvar::rent::ID,
+                associated_token_program: spl_associated_token_account::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::MintCredential {
+                skill_name: skill_name.to_string(),
+                issue_date: Clock::default().unix_timestamp,
+                credential_uri: "https://example.com/credentials/welding.json".to_string(),
+                expires_at: None,
+                co_issuers: vec![],
+            }
+            .data(),
+        };
+        (ix, credential_account)
+    };
+    let expect_error = |err: TransactionError, error: credVault::CredVaultError| {
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::Custom(error as u32 + 6000))
+        );
+    };
+
+    let student1 = Keypair::new();
+    let (ix, credential) = mint_ix(&student1.pubkey(), "Welding");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&instructor.pubkey()),
+        &[&instructor, &student1],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let credential = ctx.banks_client.get_account(credential).await.unwrap().unwrap();
+    let credential = credVault::CredentialAccount::try_deserialize(&mut credential.data.as_ref()).unwrap();
+    assert_eq!(credential.issued_by, Some(instructor.pubkey()));
+
+    // Skills outside the delegation are refused
+    let student2 = Keypair::new();
+    let (ix, _) = mint_ix(&student2.pubkey(), "Nursing");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&instructor.pubkey()),
+        &[&instructor, &student2],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    expect_error(err, credVault::CredVaultError::SkillNotDelegated);
+
+    // The daily quota is used up
+    let (ix, _) = mint_ix(&student2.pubkey(), "Welding");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&instructor.pubkey()),
+        &[&instructor, &student2],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    expect_error(err, credVault::CredVaultError::DelegateQuotaExceeded);
+}
+
+#[tokio::test]
+async fn test_multisig_issuer_revocation_needs_officer_approvals() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Law", None).await;
+
+    // The institution switches to 2-of-3 officer approval
+    let officers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (multisig, _) =
+        Pubkey::find_program_address(&[b"multisig", issuer_account_key.as_ref()], &credVault::ID);
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &officers[0].pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ConfigureIssuerMultisig {
+                issuer_account: issuer_account_key,
+                multisig,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ConfigureIssuerMultisig {
+                officers: officers.iter().map(|officer| officer.pubkey()).collect(),
+                threshold: 2,
+            }
+            .data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let (operation, _) = Pubkey::find_program_address(
+        &[b"issuer-op", multisig.as_ref(), 0u64.to_le_bytes().as_ref()],
+        &credVault::ID,
+    );
+    let revoke_ix = |signer: Pubkey, with_operation: bool| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RevokeCredential {
+            credential_account: credential,
+            issuer_account: issuer_account_key,
+            issuer: signer,
+            delegate: None,
+            multisig: with_operation.then_some(multisig),
+            operation: with_operation.then_some(operation),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RevokeCredential {
+            reason_code: 1,
+            reason_uri: "https://example.com/revocations/1.json".to_string(),
+        }
+        .data(),
+    };
+    let expect_error = |err: TransactionError, error: credVault::CredVaultError| {
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(0, InstructionError::Custom(error as u32 + 6000))
+        );
+    };
+
+    // The issuer's authority can no longer revoke on its own
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix(issuer.pubkey(), false)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    expect_error(err, credVault::CredVaultError::MultisigRequired);
+
+    // One officer proposes; a single approval is not enough
+    let propose_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ProposeIssuerOperation {
+            multisig,
+            operation,
+            officer: officers[0].pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ProposeIssuerOperation {
+            kind: credVault::IssuerOperationKind::RevokeCredential {
+                credential,
+                reason_code: 1,
+                reason_uri: "https://example.com/revocations/1.json".to_string(),
+            },
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix],
+        Some(&officers[0].pubkey()),
+        &[&officers[0]],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix(officers[0].pubkey(), true)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &officers[0]],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    expect_error(err, credVault::CredVaultError::InsufficientApprovals);
+
+    // A second officer approves and the third executes
+    let approve_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ApproveIssuerOperation {
+            multisig,
+            operation,
+            officer: officers[1].pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ApproveIssuerOperation {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            approve_ix,
+            fund_ix(&ctx.payer.pubkey(), &officers[2].pubkey()),
+            revoke_ix(officers[2].pubkey(), true),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &officers[1], &officers[2]],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let credential = ctx.banks_client.get_account(credential).await.unwrap().unwrap();
+    let credential = credVault::CredentialAccount::try_deserialize(&mut credential.data.as_ref()).unwrap();
+    assert!(credential.is_revoked);
+
+    let operation = ctx.banks_client.get_account(operation).await.unwrap().unwrap();
+    let operation = credVault::IssuerOperation::try_deserialize(&mut operation.data.as_ref()).unwrap();
+    assert!(operation.executed);
+}
+
+#[tokio::test]
+async fn test_co_issued_credential_needs_every_countersignature() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let university = Keypair::new();
+    let company = Keypair::new();
+    let student = Keypair::new();
+    let university_account = setup_verified_issuer(&mut ctx, config_key, &admin, &university).await;
+    let company_account = setup_verified_issuer_with_proposal(&mut ctx, config_key, &admin, &company, 1).await;
+
+    let (credential, _) = mint_co_issued_token_2022_credential(
+        &mut ctx,
+        university_account,
+        &university,
+        &student,
+        "Data Engineering",
+        None,
+        vec![company.pubkey()],
+    )
+    .await;
+
+    // Not valid until the company countersigns
+    let result =
+        simulate_verify_credential_with_accounts(&mut ctx, credential, university_account, None, &[company_account]).await;
+    assert_eq!(result.status, credVault::CredentialStatus::AwaitingCountersignature);
+    assert_eq!(result.co_issuers.len(), 1);
+    assert_eq!(result.co_issuers[0].issuer_pubkey, company.pubkey());
+    assert_eq!(result.co_issuers[0].signed_at, None);
+
+    let countersign_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::CountersignCredential {
+            credential_account: credential,
+            issuer_account: company_account,
+            authority: company.pubkey(),
+            delegate: None,
+            multisig: None,
+            operation: None,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::CountersignCredential {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[countersign_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &company],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result =
+        simulate_verify_credential_with_accounts(&mut ctx, credential, university_account, None, &[company_account]).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert!(result.co_issuers[0].signed_at.is_some());
+
+    // The company can revoke on its own
+    let revoke_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RevokeCredential {
+            credential_account: credential,
+            issuer_account: company_account,
+            issuer: company.pubkey(),
+            delegate: None,
+            multisig: None,
+            operation: None,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RevokeCredential {
+            reason_code: 1,
+            reason_uri: "https://example.com/revocations/1.json".to_string(),
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &company],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result =
+        simulate_verify_credential_with_accounts(&mut ctx, credential, university_account, None, &[company_account]).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Revoked);
+}
+
+#[tokio::test]
+async fn test_accreditation_chain_and_revocation_cascade() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let accreditor = Keypair::new();
+    let university = Keypair::new();
+    let student = Keypair::new();
+    let accreditor_account = setup_verified_issuer(&mut ctx, config_key, &admin, &accreditor).await;
+
+    let (university_account, university_bump) =
+        Pubkey::find_program_address(&[b"issuer", university.pubkey().as_ref()], &credVault::ID);
+    let accredit_ix = |child_account: Pubkey| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::AccreditIssuer {
+            parent_account: accreditor_account,
+            child_account,
+            authority: accreditor.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::AccreditIssuer { path_len: 1 }.data(),
+    };
+
+    // Verification alone does not let a root accredit others
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            fund_ix(&ctx.payer.pubkey(), &university.pubkey()),
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::InitializeIssuer {
+                    issuer_account: university_account,
+                    authority: university.pubkey(),
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::InitializeIssuer { bump: university_bump }.data(),
+            },
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &university],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accredit_ix(university_account)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &accreditor],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::AccreditationDepthExceeded as u32 + 6000),
+        )
+    );
+
+    // Governance grants the accreditation body two levels, then it verifies the university
+    let proposal = proposal_key(1);
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::ProposeAccreditationRights {
+                    config: config_key,
+                    proposal,
+                    issuer_account: accreditor_account,
+                    proposer: admin.pubkey(),
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::ProposeAccreditationRights { path_len: 2 }.data(),
+            },
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::ExecuteProposal {
+                    config: config_key,
+                    proposal,
+                    issuer_account: Some(accreditor_account),
+                    application: None,
+                    issuer_name: None,
+                    issuer_stake: None,
+                    recipient: None,
+                    treasury: None,
+                    fraud_report: None,
+                    credential_account: None,
+                    appeal: None,
+                    executor: admin.pubkey(),
+                    system_program: None,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::ExecuteProposal {}.data(),
+            },
+            accredit_ix(university_account),
+        ],
+        Some(&admin.pubkey()),
+        &[&admin, &accreditor],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // An issuer the admins verified directly cannot be taken over as a sub-issuer
+    let other_root = Keypair::new();
+    let other_root_account =
+        setup_verified_issuer_with_proposal(&mut ctx, config_key, &admin, &other_root, 2).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[accredit_ix(other_root_account)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &accreditor],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::IssuerNotPending as u32 + 6000),
+        )
+    );
+
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, university_account, &university, &student, "Medicine", None).await;
+
+    let result =
+        simulate_verify_credential_with_accounts(&mut ctx, credential, university_account, None, &[accreditor_account])
+            .await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    let chain: Vec<_> = result.issuer_chain.iter().map(|link| (link.issuer_pubkey, link.depth)).collect();
+    assert_eq!(chain, vec![(university.pubkey(), 1), (accreditor.pubkey(), 0)]);
+
+    // Admins revoke the accreditor; the university's credentials stop verifying at once
+    let proposal = proposal_key(3);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeIssuerStatus {
+                config: config_key,
+                proposal,
+                issuer_account: accreditor_account,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeIssuerStatus { status: credVault::IssuerStatus::Revoked }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: Some(accreditor_account),
+                application: None,
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result =
+        simulate_verify_credential_with_accounts(&mut ctx, credential, university_account, None, &[accreditor_account])
+            .await;
+    assert_eq!(result.status, credVault::CredentialStatus::AccreditationInvalid);
+
+    // A revoked accreditor no longer manages its sub-issuers
+    let set_status_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::SetSubIssuerStatus {
+            parent_account: accreditor_account,
+            child_account: university_account,
+            authority: accreditor.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::SetSubIssuerStatus { status: credVault::IssuerStatus::Verified }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[set_status_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &accreditor],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::IssuerNotVerified as u32 + 6000),
+        )
+    );
+
+    // Anyone can then cascade the revocation to the university itself
+    let cascade_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::CascadeIssuerRevocation {
+            parent_account: accreditor_account,
+            child_account: university_account,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::CascadeIssuerRevocation {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(&[cascade_ix], Some(&ctx.payer.pubkey()), &[&ctx.payer], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result =
+        simulate_verify_credential_with_accounts(&mut ctx, credential, university_account, None, &[accreditor_account])
+            .await;
+    assert_eq!(result.status, credVault::CredentialStatus::IssuerRevoked);
+}
+
+#[tokio::test]
+async fn test_verifiers_apply_their_own_trust_registry() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Accounting", None).await;
+
+    // An employers' association endorses the issuer; a ministry keeps its own, empty list
+    let employers = Keypair::new();
+    let ministry = Keypair::new();
+    let registry_key = |owner: &Keypair, name: &str| {
+        Pubkey::find_program_address(
+            &[b"trust-registry", owner.pubkey().as_ref(), name.as_bytes()],
+            &credVault::ID,
+        )
+        .0
+    };
+    let employers_registry = registry_key(&employers, "ng-employers");
+    let ministry_registry = registry_key(&ministry, "ke-ministry");
+    let create_ix = |owner: &Keypair, registry: Pubkey, name: &str| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::CreateTrustRegistry {
+            registry,
+            authority: owner.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::CreateTrustRegistry {
+            name: name.to_string(),
+            maintainers: vec![owner.pubkey()],
+        }
+        .data(),
+    };
+    let (entry, _) = Pubkey::find_program_address(
+        &[b"registry-entry", employers_registry.as_ref(), issuer.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &employers.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &ministry.pubkey()),
+        create_ix(&employers, employers_registry, "ng-employers"),
+        create_ix(&ministry, ministry_registry, "ke-ministry"),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::AddRegistryIssuer {
+                registry: employers_registry,
+                issuer_account: issuer_account_key,
+                entry,
+                maintainer: employers.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::AddRegistryIssuer {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &employers, &ministry],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential_in_registry(
+        &mut ctx,
+        credential,
+        issuer_account_key,
+        issuer.pubkey(),
+        employers_registry,
+    )
+    .await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.registry, Some(employers_registry));
+
+    let result = simulate_verify_credential_in_registry(
+        &mut ctx,
+        credential,
+        issuer_account_key,
+        issuer.pubkey(),
+        ministry_registry,
+    )
+    .await;
+    assert_eq!(result.status, credVault::CredentialStatus::IssuerNotInRegistry);
+
+    // Without a registry the admins' verification applies as before
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.registry, None);
+}
+
+#[tokio::test]
+async fn test_issuer_name_is_unique_and_shown_once_approved() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Frontend", None).await;
+
+    let name_key = |name: &str| {
+        Pubkey::find_program_address(
+            &[b"issuer-name", credVault::IssuerName::seed(name).as_ref()],
+            &credVault::ID,
+        )
+        .0
+    };
+    let claim_ix = |issuer: &Keypair, issuer_account: Pubkey, name: &str| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ClaimIssuerName {
+            issuer_account,
+            issuer_name: name_key(name),
+            authority: issuer.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ClaimIssuerName { name: name.to_string() }.data(),
+    };
+
+    // Names must be claimed in normalized form
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&issuer, issuer_account_key, "CodeCamp.ng")],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::InvalidIssuerName as u32 + 6000),
+        )
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix(&issuer, issuer_account_key, "codecamp.ng")],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let verify_accounts = || {
+        credVault::accounts::VerifyCredential {
+            credential_account: credential,
+            issuer: issuer_account_key,
+            credential_mint: None,
+            registry: None,
+            registry_entry: None,
+            issuer_name: Some(name_key("codecamp.ng")),
+            issuer_stake: None,
+        }
+        .to_account_metas(None)
+    };
+    let result = simulate_verification(&mut ctx, verify_accounts()).await;
+    assert_eq!(result.issuer_name, None);
+
+    // An impostor cannot take the same name
+    let impostor = Keypair::new();
+    let (impostor_account, impostor_bump) =
+        Pubkey::find_program_address(&[b"issuer", impostor.pubkey().as_ref()], &credVault::ID);
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &impostor.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::InitializeIssuer {
+                issuer_account: impostor_account,
+                authority: impostor.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::InitializeIssuer { bump: impostor_bump }.data(),
+        },
+        claim_ix(&impostor, impostor_account, "codecamp.ng"),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &impostor],
+        ctx.last_blockhash,
+    );
+    assert!(ctx.banks_client.process_transaction(tx).await.is_err());
+
+    // Admins approve the claim
+    let proposal = proposal_key(1);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeNameApproval {
+                config: config_key,
+                proposal,
+                issuer_name: name_key("codecamp.ng"),
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeNameApproval {}.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: None,
+                application: None,
+                issuer_name: Some(name_key("codecamp.ng")),
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verification(&mut ctx, verify_accounts()).await;
+    assert_eq!(result.issuer_name, Some("codecamp.ng".to_string()));
+
+    // A lookalike claim is rejected through a proposal, which frees the name again
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &impostor.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::InitializeIssuer {
+                issuer_account: impostor_account,
+                authority: impostor.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::InitializeIssuer { bump: impostor_bump }.data(),
+        },
+        claim_ix(&impostor, impostor_account, "codecamp-ng.com"),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &impostor],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let proposal = proposal_key(2);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeNameRejection {
+                config: config_key,
+                proposal,
+                issuer_name: name_key("codecamp-ng.com"),
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeNameRejection {}.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: Some(impostor_account),
+                application: None,
+                issuer_name: Some(name_key("codecamp-ng.com")),
+                issuer_stake: None,
+                recipient: Some(impostor.pubkey()),
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(ctx.banks_client.get_account(name_key("codecamp-ng.com")).await.unwrap().is_none());
+    let impostor_issuer = ctx.banks_client.get_account(impostor_account).await.unwrap().unwrap();
+    let impostor_issuer = credVault::IssuerAccount::try_deserialize(&mut impostor_issuer.data.as_ref()).unwrap();
+    assert_eq!(impostor_issuer.name_account, None);
+}
+
+#[tokio::test]
+async fn test_lapsed_issuer_verification_blocks_minting_until_renewed() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &Keypair::new(), "Plumbing", None).await;
+
+    let issuer_account = ctx.banks_client.get_account(issuer_account_key).await.unwrap().unwrap();
+    let issuer_account = credVault::IssuerAccount::try_deserialize(&mut issuer_account.data.as_ref()).unwrap();
+    let verified_until = issuer_account.verified_until.unwrap();
+
+    // A year on, the accreditation has not been renewed
+    warp_clock_to(&mut ctx, verified_until).await;
+    let student = Keypair::new();
+    let (credential_account, _) = Pubkey::find_program_address(
+        &[b"credential", student.pubkey().as_ref(), issuer.pubkey().as_ref(), b"Gas Fitting"],
+        &credVault::ID,
+    );
+    let (credential_mint, _) =
+        Pubkey::find_program_address(&[b"mint", credential_account.as_ref()], &credVault::ID);
+    let (program_authority, _) = Pubkey::find_program_address(&[b"authority"], &credVault::ID);
+    let mint_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::MintCredential {
+            credential_account,
+            issuer: issuer_account_key,
+            authority: issuer.pubkey(),
+            delegate: None,
+            multisig: None,
+            operation: None,
+            student: student.pubkey(),
+            token_metadata: None,
+            credential_mint,
+            credential_token_account: spl_associated_token_account::get_associated_token_address_with_program_id(
+                &student.pubkey(),
+                &credential_mint,
+                &spl_token_2022::ID,
+            ),
+            program_authority,
+            metadata_program: mpl_token_metadata::ID,
+            token_program: spl_token_2022::ID,
+            system_program: solana_sdk::system_program::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: spl_associated_token_account::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::MintCredential {
+            skill_name: "Gas Fitting".to_string(),
+            issue_date: verified_until,
+            credential_uri: "https://example.com/credentials/gas-fitting.json".to_string(),
+            expires_at: None,
+            co_issuers: vec![],
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix.clone()],
+        Some(&issuer.pubkey()),
+        &[&issuer, &student],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::VerificationLapsed as u32 + 6000),
+        )
+    );
+
+    // Credentials from the valid window still verify, but the issuer is no longer reported verified
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert!(!result.issuer_verified);
+
+    // Governance renews the verification and minting resumes
+    let proposal = proposal_key(1);
+    let propose_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ProposeVerificationRenewal {
+            config: config_key,
+            proposal,
+            issuer_account: issuer_account_key,
+            proposer: admin.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ProposeVerificationRenewal {
+            verified_until: verified_until + credVault::VERIFICATION_PERIOD,
+        }
+        .data(),
+    };
+    let execute_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ExecuteProposal {
+            config: config_key,
+            proposal,
+            issuer_account: Some(issuer_account_key),
+            application: None,
+            issuer_name: None,
+            issuer_stake: None,
+            recipient: None,
+            treasury: None,
+            fraud_report: None,
+            credential_account: None,
+            appeal: None,
+            executor: admin.pubkey(),
+            system_program: None,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ExecuteProposal {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix, execute_ix, mint_ix],
+        Some(&issuer.pubkey()),
+        &[&issuer, &student, &admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert!(result.issuer_verified);
+    assert_eq!(result.issuer_verified_until, Some(verified_until + credVault::VERIFICATION_PERIOD));
+}
+
+#[tokio::test]
+async fn test_issuer_stake_unbonds_and_is_slashed_by_governance() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+
+    let (issuer_account_key, bump) = Pubkey::find_program_address(
+        &[b"issuer", issuer.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &credVault::ID);
+    let (issuer_stake, _) =
+        Pubkey::find_program_address(&[b"stake", issuer_account_key.as_ref()], &credVault::ID);
+    let (application, _) = Pubkey::find_program_address(
+        &[b"application", issuer_account_key.as_ref(), 0u32.to_le_bytes().as_ref()],
+        &credVault::ID,
+    );
+
+    // The issuer bonds 0.3 SOL with its application
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &issuer.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &admin.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::InitializeIssuer {
+                issuer_account: issuer_account_key,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::InitializeIssuer { bump }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ApplyForVerification {
+                issuer_account: issuer_account_key,
+                application,
+                treasury,
+                issuer_stake,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ApplyForVerification {
+                organization_name: "Abuja Nursing School".to_string(),
+                document_uri: "https://example.com/license.pdf".to_string(),
+                document_hash: [3; 32],
+                fee: None,
+                stake: 300_000_000,
+            }
+            .data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let unstake_ix = |amount: u64| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RequestUnstake {
+            issuer_account: issuer_account_key,
+            issuer_stake,
+            authority: issuer.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RequestUnstake { amount }.data(),
+    };
+    let withdraw_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::WithdrawStake {
+            issuer_account: issuer_account_key,
+            issuer_stake,
+            authority: issuer.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::WithdrawStake {}.data(),
+    };
+
+    // While the application is open the minimum stake must stay bonded
+    let tx = Transaction::new_signed_with_payer(
+        &[unstake_ix(250_000_000)],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::InsufficientStake as u32 + 6000),
+        )
+    );
+
+    // Unbonded stake cannot be withdrawn before the delay
+    let tx = Transaction::new_signed_with_payer(
+        &[unstake_ix(100_000_000)],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix.clone()],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::StakeUnbonding as u32 + 6000),
+        )
+    );
+
+    // A fraud case is opened, which holds the stake even once the delay has passed
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ResolveStakeCase {
+                config: config_key,
+                issuer_stake,
+                admin: admin.pubkey(),
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::OpenStakeCase {}.data(),
+        }],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let stake = ctx.banks_client.get_account(issuer_stake).await.unwrap().unwrap();
+    let stake = credVault::IssuerStake::try_deserialize(&mut stake.data.as_ref()).unwrap();
+    warp_clock_to(&mut ctx, stake.unbonding_available_at.unwrap()).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix.clone()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::PendingStakeCase as u32 + 6000),
+        )
+    );
+
+    // Fraud is proven: governance slashes 0.25 SOL to the treasury, taking all of the bonded
+    // stake and part of the unbonding stake, and the issuer withdraws what is left
+    let proposal = proposal_key(0);
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeStakeSlash {
+                config: config_key,
+                proposal,
+                issuer_stake,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeStakeSlash {
+                amount: 250_000_000,
+                recipient: treasury,
+            }
+            .data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal,
+                issuer_account: None,
+                application: None,
+                issuer_name: None,
+                issuer_stake: Some(issuer_stake),
+                recipient: Some(treasury),
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+        withdraw_ix,
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin, &issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let stake = ctx.banks_client.get_account(issuer_stake).await.unwrap().unwrap();
+    let stake = credVault::IssuerStake::try_deserialize(&mut stake.data.as_ref()).unwrap();
+    assert_eq!(stake.amount, 0);
+    assert_eq!(stake.unbonding_amount, 0);
+    assert_eq!(stake.pending_cases, 0);
+    assert_eq!(stake.total_slashed, 250_000_000);
+    assert_eq!(ctx.banks_client.get_balance(treasury).await.unwrap(), 250_000_000);
+}
+
+#[tokio::test]
+async fn test_suspended_or_reported_issuer_cannot_unbond_stake() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let rival = Keypair::new();
+
+    let (issuer_account_key, bump) = Pubkey::find_program_address(
+        &[b"issuer", issuer.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &credVault::ID);
+    let (issuer_stake, _) =
+        Pubkey::find_program_address(&[b"stake", issuer_account_key.as_ref()], &credVault::ID);
+    let (application, _) = Pubkey::find_program_address(
+        &[b"application", issuer_account_key.as_ref(), 0u32.to_le_bytes().as_ref()],
+        &credVault::ID,
+    );
+    let (fraud_report, _) = Pubkey::find_program_address(
+        &[b"fraud-report", issuer_account_key.as_ref(), rival.pubkey().as_ref()],
+        &credVault::ID,
+    );
+
+    // The issuer bonds 0.3 SOL and is verified through its application
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &issuer.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &admin.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &rival.pubkey()),
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::InitializeIssuer {
+                issuer_account: issuer_account_key,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::InitializeIssuer { bump }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ApplyForVerification {
+                issuer_account: issuer_account_key,
+                application,
+                treasury,
+                issuer_stake,
+                authority: issuer.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ApplyForVerification {
+                organization_name: "Kano Pharmacy College".to_string(),
+                document_uri: "https://example.com/charter.pdf".to_string(),
+                document_hash: [5; 32],
+                fee: None,
+                stake: 300_000_000,
+            }
+            .data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeApplicationApproval {
+                config: config_key,
+                proposal: proposal_key(0),
+                application,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeApplicationApproval { reason_code: 1 }.data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal: proposal_key(0),
+                issuer_account: Some(issuer_account_key),
+                application: Some(application),
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: None,
+                treasury: None,
+                fraud_report: None,
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+        // A rival reports the issuer
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ReportCredential {
+                issuer_account: issuer_account_key,
+                credential_account: None,
+                fraud_report,
+                reporter: rival.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ReportCredential {
+                evidence_uri: "https://example.com/complaint.pdf".to_string(),
+                evidence_hash: [6; 32],
+                bond: None,
+            }
+            .data(),
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &issuer, &admin, &rival],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let unstake_ix = |amount: u64| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::RequestUnstake {
+            issuer_account: issuer_account_key,
+            issuer_stake,
+            authority: issuer.pubkey(),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::RequestUnstake { amount }.data(),
+    };
+    let stake_under_investigation = TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(credVault::CredVaultError::StakeUnderInvestigation as u32 + 6000),
+    );
+
+    // Even stake above the minimum stays bonded while the report is open
+    let tx = Transaction::new_signed_with_payer(
+        &[unstake_ix(100_000_000)],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(err, stake_under_investigation);
+
+    // Once the report is dismissed the issuer can unbond again
+    let instructions = [
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ProposeFraudReportResolution {
+                config: config_key,
+                proposal: proposal_key(1),
+                fraud_report,
+                proposer: admin.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ProposeFraudReportResolution {
+                resolution: credVault::ReportStatus::Dismissed,
+                reason_code: 3,
+            }
+            .data(),
+        },
+        Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::ExecuteProposal {
+                config: config_key,
+                proposal: proposal_key(1),
+                issuer_account: Some(issuer_account_key),
+                application: None,
+                issuer_name: None,
+                issuer_stake: None,
+                recipient: Some(rival.pubkey()),
+                treasury: None,
+                fraud_report: Some(fraud_report),
+                credential_account: None,
+                appeal: None,
+                executor: admin.pubkey(),
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::ExecuteProposal {}.data(),
+        },
+        unstake_ix(50_000_000),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&admin.pubkey()),
+        &[&admin, &issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // A suspended issuer cannot unbond anything
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::SuspendIssuer {
+                config: config_key,
+                issuer_account: issuer_account_key,
+                admin: admin.pubkey(),
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::SuspendIssuer { effective_at: None }.data(),
+        }],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[unstake_ix(60_000_000)],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(err, stake_under_investigation);
+
+    let stake = ctx.banks_client.get_account(issuer_stake).await.unwrap().unwrap();
+    let stake = credVault::IssuerStake::try_deserialize(&mut stake.data.as_ref()).unwrap();
+    assert_eq!(stake.amount, 250_000_000);
+    assert_eq!(stake.unbonding_amount, 50_000_000);
+}
+
+#[tokio::test]
+async fn test_fraud_reports_are_visible_until_resolved() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &Keypair::new(), "Nursing", None).await;
+
+    let employer = Keypair::new();
+    let rival = Keypair::new();
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &credVault::ID);
+    let report_key = |target: Pubkey, reporter: Pubkey| {
+        Pubkey::find_program_address(&[b"fraud-report", target.as_ref(), reporter.as_ref()], &credVault::ID).0
+    };
+    let credential_report = report_key(credential, employer.pubkey());
+    let issuer_report = report_key(issuer_account_key, rival.pubkey());
+
+    // An employer reports the credential as forged, and a rival reports the issuer itself,
+    // each posting a bond
+    let report_ix = |reporter: &Keypair, credential_account: Option<Pubkey>, fraud_report: Pubkey| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ReportCredential {
+            issuer_account: issuer_account_key,
+            credential_account,
+            fraud_report,
+            reporter: reporter.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ReportCredential {
+            evidence_uri: "https://example.com/evidence.pdf".to_string(),
+            evidence_hash: [9; 32],
+            bond: Some(10_000_000),
+        }
+        .data(),
+    };
+    let instructions = [
+        fund_ix(&ctx.payer.pubkey(), &employer.pubkey()),
+        fund_ix(&ctx.payer.pubkey(), &rival.pubkey()),
+        report_ix(&employer, Some(credential), credential_report),
+        report_ix(&rival, None, issuer_report),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &employer, &rival],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.open_reports, 1);
+    assert_eq!(result.issuer_open_reports, 1);
+
+    // Governance confirms the forgery and revokes the credential, and dismisses the issuer
+    // report; a competing proposal to suspend the issuer is also opened
+    let propose_ix = |id: u64, fraud_report: Pubkey, resolution: credVault::ReportStatus| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ProposeFraudReportResolution {
+            config: config_key,
+            proposal: proposal_key(id),
+            fraud_report,
+            proposer: admin.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ProposeFraudReportResolution { resolution, reason_code: 4 }.data(),
+    };
+    let execute_ix = |id: u64, fraud_report: Pubkey, credential_account: Option<Pubkey>, reporter: Pubkey| Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::ExecuteProposal {
+            config: config_key,
+            proposal: proposal_key(id),
+            issuer_account: Some(issuer_account_key),
+            application: None,
+            issuer_name: None,
+            issuer_stake: None,
+            recipient: Some(reporter),
+            treasury: Some(treasury),
+            fraud_report: Some(fraud_report),
+            credential_account,
+            appeal: None,
+            executor: admin.pubkey(),
+            system_program: Some(solana_sdk::system_program::ID),
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::ExecuteProposal {}.data(),
+    };
+
+    // Only credential reports can revoke
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(1, issuer_report, credVault::ReportStatus::CredentialRevoked)],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::InvalidReportResolution as u32 + 6000),
+        )
+    );
+
+    // An upheld report must open a stake case. This issuer never staked, so the executor
+    // passes the uninitialised vault address; leaving it out is rejected
+    let (issuer_stake, _) =
+        Pubkey::find_program_address(&[b"stake", issuer_account_key.as_ref()], &credVault::ID);
+    let with_stake_vault = |mut instruction: Instruction| {
+        instruction.accounts.push(AccountMeta::new_readonly(issuer_stake, false));
+        instruction
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            propose_ix(1, credential_report, credVault::ReportStatus::CredentialRevoked),
+            execute_ix(1, credential_report, Some(credential), employer.pubkey()),
+        ],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(credVault::CredVaultError::IssuerStakeRequired as u32 + 6000),
+        )
+    );
+
+    let employer_balance = ctx.banks_client.get_balance(employer.pubkey()).await.unwrap();
+    let credential_report_balance = ctx.banks_client.get_balance(credential_report).await.unwrap();
+    let issuer_report_balance = ctx.banks_client.get_balance(issuer_report).await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            propose_ix(1, credential_report, credVault::ReportStatus::CredentialRevoked),
+            propose_ix(2, issuer_report, credVault::ReportStatus::Dismissed),
+            propose_ix(3, issuer_report, credVault::ReportStatus::IssuerSuspended),
+            with_stake_vault(execute_ix(1, credential_report, Some(credential), employer.pubkey())),
+            execute_ix(2, issuer_report, None, rival.pubkey()),
+        ],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Revoked);
+    let revocation = result.revocation.unwrap();
+    assert_eq!(revocation.uri.as_deref(), Some("https://example.com/evidence.pdf"));
+    assert_eq!(revocation.actor, admin.pubkey());
+    assert_eq!(result.open_reports, 0);
+    assert_eq!(result.issuer_open_reports, 0);
+
+    // Both reports are closed. The upheld one's bond and rent go back to the reporter; the
+    // dismissed one's bond is forfeited to the treasury along with its rent
+    assert!(ctx.banks_client.get_account(credential_report).await.unwrap().is_none());
+    assert!(ctx.banks_client.get_account(issuer_report).await.unwrap().is_none());
+    assert_eq!(
+        ctx.banks_client.get_balance(employer.pubkey()).await.unwrap(),
+        employer_balance + credential_report_balance
+    );
+    assert_eq!(ctx.banks_client.get_balance(treasury).await.unwrap(), issuer_report_balance);
+
+    // The competing proposal can no longer be executed once the report is resolved
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix(3, issuer_report, None, rival.pubkey())],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(anchor_lang::error::ErrorCode::AccountNotInitialized as u32),
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_student_appeal_can_overturn_revocation() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let student = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+    let (credential, _) =
+        mint_token_2022_credential(&mut ctx, issuer_account_key, &issuer, &student, "Accounting", None).await;
+    let (appeal, _) = Pubkey::find_program_address(&[b"appeal", credential.as_ref()], &credVault::ID);
+
+    // The issuer revokes, citing a reason
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::RevokeCredential {
+                credential_account: credential,
+                issuer_account: issuer_account_key,
+                issuer: issuer.pubkey(),
+                delegate: None,
+                multisig: None,
+                operation: None,
+                system_program: solana_sdk::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::RevokeCredential {
+                reason_code: 7,
+                reason_uri: "https://example.com/revocations/exam-misconduct.json".to_string(),
+            }
+            .data(),
+        }],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Revoked);
+    let revocation = result.revocation.unwrap();
+    assert_eq!(revocation.reason_code, Some(7));
+    assert_eq!(revocation.uri.as_deref(), Some("https://example.com/revocations/exam-misconduct.json"));
+    assert_eq!(revocation.actor, issuer.pubkey());
+    assert!(!result.appeal_pending);
+
+    // The student appeals
+    let appeal_ix = Instruction {
+        program_id: credVault::ID,
+        accounts: credVault::accounts::FileAppeal {
+            credential_account: credential,
+            appeal,
+            student: student.pubkey(),
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: credVault::instruction::FileAppeal {
+            statement_uri: "https://example.com/appeals/accounting.json".to_string(),
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[fund_ix(&ctx.payer.pubkey(), &student.pubkey()), appeal_ix.clone()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &student],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Revoked);
+    assert!(result.appeal_pending);
+
+    // Governance overturns the revocation, restoring the credential
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::ProposeAppealResolution {
+                    config: config_key,
+                    proposal: proposal_key(1),
+                    appeal,
+                    proposer: admin.pubkey(),
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::ProposeAppealResolution { overturn: true, reason_code: 2 }.data(),
+            },
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::ExecuteProposal {
+                    config: config_key,
+                    proposal: proposal_key(1),
+                    issuer_account: None,
+                    application: None,
+                    issuer_name: None,
+                    issuer_stake: None,
+                    recipient: Some(student.pubkey()),
+                    treasury: None,
+                    fraud_report: None,
+                    credential_account: Some(credential),
+                    appeal: Some(appeal),
+                    executor: admin.pubkey(),
+                    system_program: Some(solana_sdk::system_program::ID),
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::ExecuteProposal {}.data(),
+            },
+        ],
+        Some(&admin.pubkey()),
+        &[&admin],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let result = simulate_verify_credential(&mut ctx, credential, issuer_account_key, None).await;
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert!(result.revocation.is_none());
+    assert!(!result.appeal_pending);
+    assert!(ctx.banks_client.get_account(appeal).await.unwrap().is_none());
+
+    // The full history stays on the credential
+    let account = ctx.banks_client.get_account(credential).await.unwrap().unwrap();
+    let account = credVault::CredentialAccount::try_deserialize(&mut account.data.as_ref()).unwrap();
+    let actions: Vec<_> = account.revocation_history.iter().map(|event| event.action).collect();
+    assert_eq!(
+        actions,
+        [
+            credVault::RevocationAction::Revoked,
+            credVault::RevocationAction::AppealFiled,
+            credVault::RevocationAction::AppealOverturned,
+        ]
+    );
+    assert_eq!(account.revocation_history[2].actor, admin.pubkey());
+
+    // A credential that is no longer revoked cannot be appealed
+    let tx = Transaction::new_signed_with_payer(
+        &[appeal_ix],
+        Some(&student.pubkey()),
+        &[&student],
+        ctx.last_blockhash,
+    );
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(credVault::CredVaultError::AppealNotAllowed as u32 + 6000),
+        )
+    );
+}
+
+// Account size of a depth 3, buffer 8 concurrent Merkle tree without canopy: header +
+// sequence number, active index and buffer size + 8 change logs + the rightmost proof
+const TREE_DEPTH: usize = 3;
+const TREE_ACCOUNT_SIZE: usize = 56 + 24 + 8 * (40 + 32 * TREE_DEPTH) + (40 + 32 * TREE_DEPTH);
+
+// Root of a tree holding `leaves` (empty leaves are zeroed), and the proof of `leaf_index`
+fn merkle_root_and_proof(leaves: &[[u8; 32]], leaf_index: usize) -> ([u8; 32], Vec<AccountMeta>) {
+    let mut level = leaves.to_vec();
+    level.resize(1 << TREE_DEPTH, [0; 32]);
+    let mut proof = Vec::new();
+    let mut index = leaf_index;
+    for _ in 0..TREE_DEPTH {
+        proof.push(AccountMeta::new_readonly(Pubkey::new_from_array(level[index ^ 1]), false));
+        level = level
+            .chunks(2)
+            .map(|pair| solana_sdk::keccak::hashv(&[&pair[0], &pair[1]]).to_bytes())
+            .collect();
+        index /= 2;
+    }
+    (level[0], proof)
+}
+
+async fn simulate_verify_compressed_credential(
+    ctx: &mut ProgramTestContext,
+    tree_config: Pubkey,
+    merkle_tree: Pubkey,
+    issuer_account: Pubkey,
+    leaves: &[[u8; 32]],
+    leaf: &credVault::CompressedCredential,
+) -> Result<credVault::VerificationResult, TransactionError> {
+    let (root, proof) = merkle_root_and_proof(leaves, leaf.leaf_index as usize);
+    let mut accounts = credVault::accounts::VerifyCompressedCredential {
+        tree_config,
+        merkle_tree,
+        issuer: issuer_account,
+        compression_program: credVault::ACCOUNT_COMPRESSION_PROGRAM_ID,
+        issuer_stake: None,
+    }
+    .to_account_metas(None);
+    accounts.extend(proof);
+    let ix = Instruction {
+        program_id: credVault::ID,
+        accounts,
+        data: credVault::instruction::VerifyCompressedCredential { root, leaf: leaf.clone() }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&ctx.payer.pubkey()), &[&ctx.payer], ctx.last_blockhash);
+    let simulation = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+    simulation.result.unwrap()?;
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    Ok(credVault::VerificationResult::try_from_slice(&return_data.data).unwrap())
+}
+
+#[tokio::test]
+async fn test_compressed_credentials_can_be_verified_and_revoked() {
+    let mut program_test = ProgramTest::new(
+        "credVault",
+        credVault::ID,
+        None,
+    );
+    program_test.add_program("spl_account_compression", credVault::ACCOUNT_COMPRESSION_PROGRAM_ID, None);
+    program_test.add_program("spl_noop", credVault::NOOP_PROGRAM_ID, None);
+
+    let admin = Keypair::new();
+    let config_key = add_config_account(&mut program_test, vec![admin.pubkey()], 1);
+
+    let mut ctx = program_test.start_with_context().await;
+    let issuer = Keypair::new();
+    let issuer_account_key = setup_verified_issuer(&mut ctx, config_key, &admin, &issuer).await;
+
+    // Pin the clock, so the leaves' created_at is known
+    let now = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    warp_clock_to(&mut ctx, now).await;
+
+    // The client allocates the tree under the compression program, then the issuer initializes it
+    let merkle_tree = Keypair::new();
+    let (tree_config, _) = Pubkey::find_program_address(
+        &[b"credential-tree", merkle_tree.pubkey().as_ref()],
+        &credVault::ID,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &ctx.payer.pubkey(),
+                &merkle_tree.pubkey(),
+                Rent::default().minimum_balance(TREE_ACCOUNT_SIZE),
+                TREE_ACCOUNT_SIZE as u64,
+                &credVault::ACCOUNT_COMPRESSION_PROGRAM_ID,
+            ),
+            Instruction {
+                program_id: credVault::ID,
+                accounts: credVault::accounts::CreateCredentialTree {
+                    tree_config,
+                    merkle_tree: merkle_tree.pubkey(),
+                    issuer: issuer_account_key,
+                    authority: issuer.pubkey(),
+                    compression_program: credVault::ACCOUNT_COMPRESSION_PROGRAM_ID,
+                    log_wrapper: credVault::NOOP_PROGRAM_ID,
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: credVault::instruction::CreateCredentialTree {
+                    max_depth: TREE_DEPTH as u32,
+                    max_buffer_size: 8,
+                }
+                .data(),
+            },
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &merkle_tree, &issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    // Issue a compressed credential to each of two students
+    let students = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut leaves = Vec::new();
+    let mut hashes = Vec::new();
+    for (leaf_index, student) in students.iter().enumerate() {
+        let leaf = credVault::CompressedCredential {
+            issuer_pubkey: issuer.pubkey(),
+            student_pubkey: *student,
+            skill_name: "Web Development".to_string(),
+            issue_date: now,
+            credential_uri: "https://example.com/credentials/web-dev.json".to_string(),
+            created_at: now,
+            is_revoked: false,
+            revoked_at: None,
+            leaf_index: leaf_index as u32,
+        };
+        let mint_ix = Instruction {
+            program_id: credVault::ID,
+            accounts: credVault::accounts::MintCompressedCredential {
+                tree_config,
+                merkle_tree: merkle_tree.pubkey(),
+                issuer: issuer_account_key,
+                authority: issuer.pubkey(),
+                compression_program: credVault::ACCOUNT_COMPRESSION_PROGRAM_ID,
+                log_wrapper: credVault::NOOP_PROGRAM_ID,
+            }
+            .to_account_metas(None),
+            data: credVault::instruction::MintCompressedCredential {
+                student: *student,
+                skill_name: leaf.skill_name.clone(),
+                issue_date: leaf.issue_date,
+                credential_uri: leaf.credential_uri.clone(),
+            }
+            .data(),
+        };
+        let tx = Transaction::new_signed_with_payer(
+            &[mint_ix],
+            Some(&issuer.pubkey()),
+            &[&issuer],
+            ctx.last_blockhash,
+        );
+        ctx.banks_client.process_transaction(tx).await.unwrap();
+        hashes.push(leaf.hash().unwrap());
+        leaves.push(leaf);
+    }
+
+    let tree = ctx.banks_client.get_account(tree_config).await.unwrap().unwrap();
+    let tree = credVault::CredentialTree::try_deserialize(&mut tree.data.as_ref()).unwrap();
+    assert_eq!(tree.num_minted, 2);
+
+    let result = simulate_verify_compressed_credential(
+        &mut ctx,
+        tree_config,
+        merkle_tree.pubkey(),
+        issuer_account_key,
+        &hashes,
+        &leaves[0],
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+    assert_eq!(result.issuer_pubkey, issuer.pubkey());
+    assert!(result.issuer_verified);
+    assert_eq!(result.skill_name, "Web Development");
+    assert_eq!(result.issuer_stake, None);
+
+    // A leaf that was never appended fails the proof check
+    let forged = credVault::CompressedCredential {
+        skill_name: "Brain Surgery".to_string(),
+        ..leaves[0].clone()
+    };
+    assert!(simulate_verify_compressed_credential(
+        &mut ctx,
+        tree_config,
+        merkle_tree.pubkey(),
+        issuer_account_key,
+        &hashes,
+        &forged,
+    )
+    .await
+    .is_err());
+
+    // The issuer revokes the first student's credential by replacing its leaf
+    let (root, proof) = merkle_root_and_proof(&hashes, 0);
+    let mut accounts = credVault::accounts::RevokeCompressedCredential {
+        tree_config,
+        merkle_tree: merkle_tree.pubkey(),
+        issuer_account: issuer_account_key,
+        issuer: issuer.pubkey(),
+        compression_program: credVault::ACCOUNT_COMPRESSION_PROGRAM_ID,
+        log_wrapper: credVault::NOOP_PROGRAM_ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(proof);
+    let revoke_ix = Instruction {
+        program_id: credVault::ID,
+        accounts,
+        data: credVault::instruction::RevokeCompressedCredential { root, leaf: leaves[0].clone() }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&issuer.pubkey()),
+        &[&issuer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let revoked = credVault::CompressedCredential {
+        is_revoked: true,
+        revoked_at: Some(now),
+        ..leaves[0].clone()
+    };
+    hashes[0] = revoked.hash().unwrap();
+
+    let result = simulate_verify_compressed_credential(
+        &mut ctx,
+        tree_config,
+        merkle_tree.pubkey(),
+        issuer_account_key,
+        &hashes,
+        &revoked,
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.status, credVault::CredentialStatus::Revoked);
+    assert_eq!(result.revoked_at, Some(now));
+
+    // The original leaf is gone, while the other student's credential is untouched
+    assert!(simulate_verify_compressed_credential(
+        &mut ctx,
+        tree_config,
+        merkle_tree.pubkey(),
+        issuer_account_key,
+        &hashes,
+        &leaves[0],
+    )
+    .await
+    .is_err());
+    let result = simulate_verify_compressed_credential(
+        &mut ctx,
+        tree_config,
+        merkle_tree.pubkey(),
+        issuer_account_key,
+        &hashes,
+        &leaves[1],
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.status, credVault::CredentialStatus::Valid);
+}